@@ -67,6 +67,18 @@ impl DbStateInner {
       .into_result()
   }
 
+  /// 获取已刮削的视频信息，不会触发刮削
+  pub async fn find_info(&mut self, code: &str) -> Result<Option<VideoInfo>> {
+    let record = self.query_one(code).await?;
+    Ok(record.and_then(|r| r.info))
+  }
+
+  /// 获取已刮削的发布日期，不会触发刮削
+  pub async fn find_release_date(&mut self, code: &str) -> Result<Option<i64>> {
+    let info = self.find_info(code).await?;
+    Ok(info.and_then(|info| info.release_date))
+  }
+
   async fn upsert_one(&mut self, video_info: VideoInfo) -> Result<()> {
     let existed = self.query_one(&video_info.code).await?;
     let db = self
@@ -6,16 +6,38 @@ mod db;
 mod error;
 mod qbittorrent;
 mod scrape;
+mod settings;
+mod torrent;
 
 use db::{DbState, get_video_info, has_been_downloaded, mark_as_downloaded, rescrape};
 use log::{LevelFilter, error};
 use scrape::{download_image, guess_movie_code};
 use tauri::{Manager, State};
-use tauri_specta::{Builder, ErrorHandlingMode, collect_commands};
+use tauri_specta::{Builder, ErrorHandlingMode, collect_commands, collect_events};
+use torrent::{check_links, preview_torrent_file};
 
 use qbittorrent::{
-  QBittorrentState, add_files, add_urls, delete, get_main_data, get_torrent_contents, initialize,
-  login, recheck, rename, set_file_priority, start, stop,
+  QBittorrentState, ServerStateChanged, TorrentAdded, TorrentCompleted, TorrentMoveFinished,
+  TorrentRemoved, TorrentStateChanged, TorrentUpdated, add_files, add_rss_feed, add_rss_folder,
+  add_tags, add_trackers, add_urls, apply_tracker_list, auto_select_files, bottom_priority,
+  compare_torrents, convert_magnet_to_torrent, create_category, create_tags, decrease_priority,
+  delete, delete_search, delete_tags, edit_category, edit_tracker, enable_search_plugin,
+  export_torrents, get_active_profile, get_app_version, get_build_info, get_categories,
+  get_main_data, get_main_data_snapshot, get_main_log, get_peer_log, get_piece_states,
+  get_preferences, get_profiles, get_rss_items, get_rss_matching_articles, get_rss_rules,
+  get_search_plugins, get_search_results, get_search_status, get_select_rules, get_tags,
+  get_torrent_caches, get_torrent_contents, get_torrent_peers, get_torrent_properties,
+  get_torrent_trackers, get_torrent_webseeds, get_tracker_list, get_webapi_version,
+  increase_priority, initialize, install_search_plugin, login, mark_rss_as_read, move_rss_item,
+  recheck, refresh_rss_item, remove_categories, remove_profile, remove_rss_item, remove_rss_rule,
+  remove_tags, remove_trackers, rename, rename_file, rename_files_to_movie_code, rename_folder,
+  rename_rss_rule, reorder_queue, save_profile, search_movie_code, set_auto_management,
+  set_category, set_download_path, set_file_priority, set_force_start, set_global_download_limit,
+  set_global_upload_limit, set_location, set_movie_code_rss_rule, set_preferences, set_rss_rule,
+  set_save_path, set_select_rules, set_share_limits, set_super_seeding, set_torrent_caches,
+  set_torrent_download_limit, set_torrent_upload_limit, set_tracker_list, start, start_search,
+  start_sync, stop, stop_search, stop_sync, switch_profile, toggle_first_last_piece_prio,
+  toggle_sequential_download, toggle_speed_limits_mode, top_priority, transfer_torrents,
 };
 
 fn main() {
@@ -23,23 +45,123 @@ fn main() {
     // Then register them (separated by a comma)
     .commands(collect_commands![
       add_files,
+      add_rss_feed,
+      add_rss_folder,
+      add_tags,
+      add_trackers,
       add_urls,
+      apply_tracker_list,
+      auto_select_files,
+      bottom_priority,
+      check_links,
+      compare_torrents,
+      convert_magnet_to_torrent,
+      create_category,
+      create_tags,
+      decrease_priority,
       delete,
+      delete_search,
+      delete_tags,
       download_image,
+      edit_category,
+      edit_tracker,
+      enable_search_plugin,
+      export_torrents,
+      get_active_profile,
+      get_app_version,
+      get_build_info,
+      get_categories,
       get_main_data,
+      get_main_data_snapshot,
+      get_main_log,
+      get_peer_log,
+      get_piece_states,
+      get_preferences,
+      get_profiles,
+      get_rss_items,
+      get_rss_matching_articles,
+      get_rss_rules,
+      get_search_plugins,
+      get_search_results,
+      get_search_status,
+      get_select_rules,
+      get_tags,
+      get_torrent_caches,
+      get_tracker_list,
+      get_webapi_version,
       guess_movie_code,
       get_torrent_contents,
+      get_torrent_peers,
+      get_torrent_properties,
+      get_torrent_trackers,
+      get_torrent_webseeds,
       get_video_info,
       has_been_downloaded,
+      increase_priority,
       initialize,
+      install_search_plugin,
       login,
       mark_as_downloaded,
+      mark_rss_as_read,
+      move_rss_item,
+      preview_torrent_file,
       recheck,
+      refresh_rss_item,
+      remove_categories,
+      remove_profile,
+      remove_rss_item,
+      remove_rss_rule,
+      remove_tags,
+      remove_trackers,
       rename,
+      rename_file,
+      rename_files_to_movie_code,
+      rename_folder,
+      rename_rss_rule,
+      reorder_queue,
       rescrape,
+      save_profile,
+      search_movie_code,
+      set_auto_management,
+      set_category,
+      set_download_path,
       set_file_priority,
+      set_force_start,
+      set_global_download_limit,
+      set_global_upload_limit,
+      set_location,
+      set_movie_code_rss_rule,
+      set_preferences,
+      set_rss_rule,
+      set_save_path,
+      set_select_rules,
+      set_share_limits,
+      set_super_seeding,
+      set_torrent_caches,
+      set_torrent_download_limit,
+      set_torrent_upload_limit,
+      set_tracker_list,
       start,
+      start_search,
+      start_sync,
       stop,
+      stop_search,
+      stop_sync,
+      switch_profile,
+      toggle_first_last_piece_prio,
+      toggle_sequential_download,
+      toggle_speed_limits_mode,
+      top_priority,
+      transfer_torrents,
+    ])
+    .events(collect_events![
+      ServerStateChanged,
+      TorrentAdded,
+      TorrentCompleted,
+      TorrentMoveFinished,
+      TorrentRemoved,
+      TorrentStateChanged,
+      TorrentUpdated,
     ])
     .error_handling(ErrorHandlingMode::Throw);
 
@@ -71,9 +193,10 @@ fn main() {
     .manage(QBittorrentState::default())
     .manage(DbState::default())
     .invoke_handler(builder.invoke_handler())
-    .setup(|app| {
+    .setup(move |app| {
       let handle = app.handle();
       app_handle::set_app_handle(handle);
+      builder.mount_events(handle);
 
       tauri::async_runtime::block_on(async move {
         let state: State<DbState> = handle.state();
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct Category {
+  /// Category name
+  pub name: Option<String>,
+  /// Default save path of torrents in this category
+  #[serde(rename = "savePath")]
+  pub save_path: Option<String>,
+}
+
+/// 获取分类
+#[tauri::command]
+#[specta::specta]
+pub async fn get_categories(
+  state: State<'_, QBittorrentState>,
+) -> Result<HashMap<String, Category>> {
+  let state = state.lock().await?;
+  state.get("torrents", "categories", None::<&()>).await
+}
+
+/// 创建分类
+#[tauri::command]
+#[specta::specta]
+pub async fn create_category(
+  state: State<'_, QBittorrentState>,
+  category: String,
+  save_path: Option<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "createCategory",
+      &[
+        ("category", category.as_str()),
+        ("savePath", save_path.as_deref().unwrap_or_default()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 编辑分类
+#[tauri::command]
+#[specta::specta]
+pub async fn edit_category(
+  state: State<'_, QBittorrentState>,
+  category: String,
+  save_path: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "editCategory",
+      &[
+        ("category", category.as_str()),
+        ("savePath", save_path.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除分类
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_categories(
+  state: State<'_, QBittorrentState>,
+  categories: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "removeCategories",
+      &[("categories", categories.join("\n").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置种子分类
+#[tauri::command]
+#[specta::specta]
+pub async fn set_category(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  category: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setCategory",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("category", category.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use log::{debug, warn};
+use reqwest::Client;
+use tauri::State;
+
+use super::{
+  AddTorrentOptions, QBittorrentError, QBittorrentState, TorrentInfo, TorrentState, error::Result,
+};
+use crate::{
+  settings::{get_setting, set_setting},
+  torrent::parse_torrent,
+};
+
+const TORRENT_CACHES_KEY: &str = "torrentCaches";
+const DEFAULT_TORRENT_CACHE: &str = "https://itorrents.org/torrent/{HASH}.torrent";
+/// 从单个缓存下载种子文件的最长时间
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 替换缓存地址中的哈希，`{HASH}` 为大写，`{hash}` 为小写
+fn cache_url(template: &str, hash: &str) -> String {
+  template
+    .replace("{HASH}", &hash.to_uppercase())
+    .replace("{hash}", &hash.to_lowercase())
+}
+
+/// 保留种子的分类、标签与保存路径
+pub(super) fn options_from(info: &TorrentInfo) -> AddTorrentOptions {
+  let auto_tmm = info.auto_tmm.unwrap_or_default();
+
+  AddTorrentOptions {
+    category: info
+      .category
+      .clone()
+      .filter(|category| !category.is_empty()),
+    tags: info.tags.clone().filter(|tags| !tags.is_empty()),
+    save_path: if auto_tmm {
+      None
+    } else {
+      info.save_path.clone()
+    },
+    auto_tmm: Some(auto_tmm),
+    stopped: Some(matches!(
+      info.state,
+      Some(TorrentState::StoppedDL | TorrentState::StoppedUP)
+    )),
+    ..Default::default()
+  }
+}
+
+/// 依次从缓存下载种子文件，返回哈希一致的种子文件与下载地址
+async fn download_from_caches(
+  client: &Client,
+  templates: &[String],
+  hash: &str,
+) -> Option<(Vec<u8>, String)> {
+  for template in templates {
+    let url = cache_url(template, hash);
+    debug!("Downloading torrent from {}", url);
+
+    let data = match client.get(&url).timeout(DOWNLOAD_TIMEOUT).send().await {
+      Ok(res) if res.status().is_success() => match res.bytes().await {
+        Ok(data) => data.to_vec(),
+        Err(e) => {
+          warn!("Failed to download {}: {}", url, e);
+          continue;
+        }
+      },
+      Ok(res) => {
+        warn!("Failed to download {}: {}", url, res.status());
+        continue;
+      }
+      Err(e) => {
+        warn!("Failed to download {}: {}", url, e);
+        continue;
+      }
+    };
+
+    match parse_torrent(&data) {
+      Ok(preview) if preview.hash().is_some_and(|h| h.eq_ignore_ascii_case(hash)) => {
+        return Some((data, url));
+      }
+      Ok(preview) => warn!("Info hash mismatch from {}: {:?}", url, preview.hash()),
+      Err(e) => warn!("Invalid torrent from {}: {}", url, e),
+    }
+  }
+
+  None
+}
+
+/// 获取种子缓存地址模板
+#[tauri::command]
+#[specta::specta]
+pub fn get_torrent_caches() -> crate::error::Result<Vec<String>> {
+  Ok(get_setting(TORRENT_CACHES_KEY)?.unwrap_or_else(|| vec![DEFAULT_TORRENT_CACHE.to_string()]))
+}
+
+/// 保存种子缓存地址模板
+#[tauri::command]
+#[specta::specta]
+pub fn set_torrent_caches(caches: Vec<String>) -> crate::error::Result<()> {
+  let caches = caches
+    .into_iter()
+    .map(|url| url.trim().to_string())
+    .filter(|url| !url.is_empty())
+    .collect::<Vec<_>>();
+  set_setting(TORRENT_CACHES_KEY, caches)
+}
+
+/// 将磁力链接转换为种子文件，返回下载种子文件的地址
+///
+/// 依次尝试缓存地址，种子文件的 info hash 一致时才删除原来的种子并重新添加，保留分类、标签与保存路径。
+/// 下载种子文件时不锁定服务器的连接。
+#[tauri::command]
+#[specta::specta]
+pub async fn convert_magnet_to_torrent(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<String> {
+  let templates = get_torrent_caches()?;
+
+  let (info, client) = {
+    let state = state.lock().await?;
+
+    let Some(info) = state
+      .torrents_info(Some(std::slice::from_ref(&hash)))
+      .await?
+      .into_iter()
+      .next()
+    else {
+      return Err(QBittorrentError::NotFound);
+    };
+
+    (info, state.client()?.clone())
+  };
+
+  let Some((data, url)) = download_from_caches(&client, &templates, &hash).await else {
+    return Err(QBittorrentError::Other(format!(
+      "Torrent not found in caches: {}",
+      hash
+    )));
+  };
+
+  let options = options_from(&info);
+  let state = state.lock().await?;
+
+  state
+    .post(
+      "torrents",
+      "delete",
+      &[("hashes", hash.as_str()), ("deleteFiles", "false")],
+    )
+    .await?;
+
+  let file_name = format!("{}.torrent", hash);
+
+  if let Err(e) = state
+    .add_torrent_files(&[(file_name, data)], &options)
+    .await
+  {
+    // 添加失败时恢复原来的磁力链接，恢复失败时仍返回添加的错误
+    if let Some(magnet_uri) = &info.magnet_uri
+      && let Err(restore_error) = state.add_torrent_urls(magnet_uri, &options).await
+    {
+      warn!(
+        "Failed to restore magnet link of {}: {}",
+        hash, restore_error
+      );
+    }
+
+    return Err(e);
+  }
+
+  Ok(url)
+}
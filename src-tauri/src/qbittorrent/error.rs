@@ -0,0 +1,110 @@
+use reqwest::{Response, StatusCode};
+use serde::Serialize;
+use specta::Type;
+
+/// qBittorrent API 错误
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(tag = "kind", content = "message", rename_all = "camelCase")]
+pub enum QBittorrentError {
+  /// 尚未设置服务器地址
+  NotInitialized,
+  /// 未登录，或会话过期后重新登录失败
+  Unauthorized,
+  /// 登录失败次数过多，IP 已被禁止
+  Banned,
+  /// 没有权限执行操作，例如保存路径不可写
+  Forbidden(String),
+  /// 找不到指定的种子
+  NotFound,
+  /// 请求与服务器状态冲突，例如分类名无效或路径无法使用
+  Conflict(String),
+  /// 种子文件无效
+  InvalidTorrent,
+  /// 网络、HTTP 或解析错误
+  Transport(String),
+  /// 找不到指定的服务器配置
+  ProfileNotFound(String),
+  /// 读取设置等其他错误
+  Other(String),
+}
+
+impl std::fmt::Display for QBittorrentError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::NotInitialized => write!(f, "qBittorrent client is not initialized"),
+      Self::Unauthorized => write!(f, "Authentication failed"),
+      Self::Banned => write!(f, "IP is banned for too many failed login attempts"),
+      Self::Forbidden(message) => write!(f, "Forbidden: {}", message),
+      Self::NotFound => write!(f, "Torrent not found"),
+      Self::Conflict(message) => write!(f, "Conflict: {}", message),
+      Self::InvalidTorrent => write!(f, "Invalid torrent"),
+      Self::Transport(message) => write!(f, "{}", message),
+      Self::ProfileNotFound(id) => write!(f, "Profile not found: {}", id),
+      Self::Other(message) => write!(f, "{}", message),
+    }
+  }
+}
+
+impl std::error::Error for QBittorrentError {}
+
+impl QBittorrentError {
+  /// 根据 HTTP 状态码转换错误
+  pub async fn check(res: Response) -> Result<Response> {
+    let status = res.status();
+
+    if status.is_success() {
+      return Ok(res);
+    }
+
+    let message = res.text().await.unwrap_or_default();
+
+    Err(match status {
+      StatusCode::UNAUTHORIZED => Self::Unauthorized,
+      // 未登录时只返回状态文本，其他 403 会带有具体原因
+      StatusCode::FORBIDDEN if message == "Forbidden" => Self::Unauthorized,
+      StatusCode::FORBIDDEN => Self::Forbidden(message),
+      StatusCode::NOT_FOUND => Self::NotFound,
+      StatusCode::CONFLICT => Self::Conflict(message),
+      StatusCode::UNSUPPORTED_MEDIA_TYPE => Self::InvalidTorrent,
+      _ => Self::Transport(format!("{}: {}", status, message)),
+    })
+  }
+}
+
+macro_rules! impl_from_transport {
+  ($($error:ty),* $(,)?) => {
+    $(
+      impl From<$error> for QBittorrentError {
+        fn from(value: $error) -> Self {
+          Self::Transport(value.to_string())
+        }
+      }
+    )*
+  };
+}
+
+impl_from_transport!(
+  reqwest::Error,
+  url::ParseError,
+  serde_json::Error,
+  serde_urlencoded::ser::Error,
+  std::io::Error,
+);
+
+impl From<QBittorrentError> for crate::error::Error {
+  fn from(value: QBittorrentError) -> Self {
+    Self(value.into())
+  }
+}
+
+/// 还原被包装的 qBittorrent 错误，其他错误转换为 `Other`
+impl From<crate::error::Error> for QBittorrentError {
+  fn from(value: crate::error::Error) -> Self {
+    match value.0.downcast::<QBittorrentError>() {
+      Ok(error) => error,
+      Err(error) => Self::Other(format!("{:#}", error)),
+    }
+  }
+}
+
+pub type Result<T> = std::result::Result<T, QBittorrentError>;
@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, Manager, State, async_runtime};
+use tauri_specta::Event;
+
+use super::{
+  QBittorrentState, QBittorrentStateInner, ServerState, TorrentInfo, TorrentState, error::Result,
+  profiles::connect, sync::SyncChange,
+};
+
+/// 默认刷新间隔（毫秒）
+const DEFAULT_REFRESH_INTERVAL: u64 = 1500;
+
+/// 添加了种子
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentAdded {
+  pub hash: String,
+  pub info: TorrentInfo,
+}
+
+/// 种子信息改变，`info` 为合并后的完整信息
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentUpdated {
+  pub hash: String,
+  pub info: TorrentInfo,
+}
+
+/// 删除了种子
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentRemoved {
+  pub hash: String,
+}
+
+/// 种子状态改变
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentStateChanged {
+  pub hash: String,
+  pub old_state: Option<TorrentState>,
+  pub new_state: Option<TorrentState>,
+}
+
+/// 种子下载完成
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentCompleted {
+  pub hash: String,
+  pub name: Option<String>,
+}
+
+/// 种子移动完成
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct TorrentMoveFinished {
+  pub hash: String,
+  pub save_path: Option<String>,
+}
+
+/// 全局传输信息改变
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Event)]
+pub struct ServerStateChanged {
+  pub server_state: ServerState,
+}
+
+/// 发送合并增量时产生的事件
+pub(super) fn emit_changes(
+  app: &AppHandle,
+  state: &QBittorrentStateInner,
+  changes: Vec<SyncChange>,
+) {
+  for change in changes {
+    let result = match change {
+      SyncChange::TorrentAdded(hash) => match state.sync.torrents.get(&hash) {
+        Some(info) => TorrentAdded {
+          info: info.clone(),
+          hash,
+        }
+        .emit(app),
+        None => Ok(()),
+      },
+      SyncChange::TorrentUpdated(hash) => match state.sync.torrents.get(&hash) {
+        Some(info) => TorrentUpdated {
+          info: info.clone(),
+          hash,
+        }
+        .emit(app),
+        None => Ok(()),
+      },
+      SyncChange::TorrentRemoved(hash) => TorrentRemoved { hash }.emit(app),
+      SyncChange::TorrentStateChanged(hash, old_state) => {
+        let info = state.sync.torrents.get(&hash);
+        let new_state = info.and_then(|info| info.state.clone());
+
+        if old_state == Some(TorrentState::Moving) && new_state != Some(TorrentState::Moving) {
+          let save_path = info.and_then(|info| info.save_path.clone());
+          let finished = TorrentMoveFinished {
+            hash: hash.clone(),
+            save_path,
+          };
+
+          if let Err(e) = finished.emit(app) {
+            warn!("Failed to emit event: {}", e);
+          }
+        }
+
+        TorrentStateChanged {
+          hash,
+          old_state,
+          new_state,
+        }
+        .emit(app)
+      }
+      SyncChange::TorrentCompleted(hash) => {
+        let name = state
+          .sync
+          .torrents
+          .get(&hash)
+          .and_then(|info| info.name.clone());
+        TorrentCompleted { hash, name }.emit(app)
+      }
+      SyncChange::ServerStateChanged => ServerStateChanged {
+        server_state: state.sync.server_state.clone(),
+      }
+      .emit(app),
+    };
+
+    if let Err(e) = result {
+      warn!("Failed to emit event: {}", e);
+    }
+  }
+}
+
+/// 清空服务器合并的数据，并为其中的种子发送删除事件
+pub(super) fn emit_reset(app: &AppHandle, state: &mut QBittorrentStateInner) {
+  let changes = state
+    .reset_sync()
+    .into_iter()
+    .map(SyncChange::TorrentRemoved)
+    .collect();
+  emit_changes(app, state, changes);
+}
+
+/// 同步一次主要数据并发送事件，返回下次同步前的等待时间
+async fn sync_once(app: &AppHandle) -> Duration {
+  let qb_state: State<QBittorrentState> = app.state();
+  let profile_id = qb_state.active_profile().await;
+  let mut state = match connect(&qb_state, &profile_id).await {
+    Ok(state) => state,
+    Err(e) => {
+      warn!("Failed to connect: {}", e);
+      return Duration::from_millis(DEFAULT_REFRESH_INTERVAL);
+    }
+  };
+
+  // 等待连接时切换了服务器配置，旧服务器的数据已清空，不再同步
+  if qb_state.active_profile().await != profile_id {
+    return Duration::ZERO;
+  }
+
+  match state.sync_main_data().await {
+    Ok(changes) => emit_changes(app, &state, changes),
+    Err(e) => warn!("Failed to sync main data: {}", e),
+  }
+
+  let interval = state
+    .sync
+    .server_state
+    .refresh_interval
+    .and_then(|interval| u64::try_from(interval).ok())
+    .unwrap_or(DEFAULT_REFRESH_INTERVAL);
+  Duration::from_millis(interval)
+}
+
+/// 开始后台同步
+#[tauri::command]
+#[specta::specta]
+pub async fn start_sync(app: AppHandle, state: State<'_, QBittorrentState>) -> Result<()> {
+  let mut task = state.sync_task.lock().await;
+
+  if task.is_none() {
+    debug!("Start syncing main data");
+    *task = Some(async_runtime::spawn(async move {
+      loop {
+        let interval = sync_once(&app).await;
+        tokio::time::sleep(interval).await;
+      }
+    }));
+  }
+
+  Ok(())
+}
+
+/// 停止后台同步
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_sync(state: State<'_, QBittorrentState>) -> Result<()> {
+  let mut task = state.sync_task.lock().await;
+
+  if let Some(task) = task.take() {
+    debug!("Stop syncing main data");
+    task.abort();
+  }
+
+  Ok(())
+}
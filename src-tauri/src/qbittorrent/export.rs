@@ -0,0 +1,145 @@
+use std::{collections::HashSet, path::Path};
+
+use log::debug;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+use crate::{db::DbState, error::IntoResult, scrape::get_movie_code};
+
+/// 文件名中标题的最大字符数
+const MAX_TITLE_CHARS: usize = 80;
+
+/// 去除文件名中不允许的字符
+fn sanitize_file_name(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| match c {
+      '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => ' ',
+      c if c.is_control() => ' ',
+      c => c,
+    })
+    .collect::<String>()
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// 种子文件名，优先使用番号与标题，其次为种子名称，最后为哈希
+fn file_name(code: Option<&str>, title: Option<&str>, name: Option<&str>, hash: &str) -> String {
+  let stem = match (code, title) {
+    (Some(code), Some(title)) => {
+      let title = title.chars().take(MAX_TITLE_CHARS).collect::<String>();
+      format!("{} {}", code, title)
+    }
+    (Some(code), None) => code.to_string(),
+    _ => name.unwrap_or(hash).to_string(),
+  };
+
+  let stem = sanitize_file_name(&stem);
+
+  if stem.is_empty() {
+    format!("{}.torrent", hash)
+  } else {
+    format!("{}.torrent", stem)
+  }
+}
+
+/// 在文件名后添加哈希的前 8 位
+fn with_hash_suffix(name: &str, hash: &str) -> String {
+  let stem = name.strip_suffix(".torrent").unwrap_or(name);
+  let suffix = hash.get(..8).unwrap_or(hash);
+  format!("{} {}.torrent", stem, suffix)
+}
+
+/// 导出种子文件到文件夹，返回保存的文件路径
+///
+/// 文件以番号与刮削的标题命名，与已有文件或本次导出的文件同名时在名称后添加哈希的前 8 位。
+#[tauri::command]
+#[specta::specta]
+pub async fn export_torrents(
+  qb_state: State<'_, QBittorrentState>,
+  db_state: State<'_, DbState>,
+  hashes: Vec<String>,
+  folder: String,
+) -> Result<Vec<String>> {
+  let state = qb_state.lock().await?;
+  let torrents = state.torrents_info(Some(&hashes)).await?;
+  let mut names = HashSet::new();
+  let mut paths = vec![];
+
+  for info in torrents {
+    let Some(hash) = info.hash else {
+      continue;
+    };
+
+    let code = info.name.as_ref().and_then(get_movie_code);
+    let title = match &code {
+      Some(code) => db_state
+        .lock()
+        .await
+        .find_info(code)
+        .await?
+        .map(|info| info.title.text),
+      None => None,
+    };
+
+    let data = state
+      .get_bytes("torrents", "export", &[("hash", hash.as_str())])
+      .await?;
+    let mut name = file_name(
+      code.as_deref(),
+      title.as_deref(),
+      info.name.as_deref(),
+      &hash,
+    );
+    let exists = tokio::fs::try_exists(Path::new(&folder).join(&name))
+      .await
+      .unwrap_or_default();
+
+    if exists || names.contains(&name) {
+      name = with_hash_suffix(&name, &hash);
+    }
+
+    let path = Path::new(&folder).join(&name);
+    names.insert(name);
+
+    debug!("Exporting {} to {}", hash, path.display());
+    tokio::fs::write(&path, data).await.into_result()?;
+    paths.push(path.to_string_lossy().to_string());
+  }
+
+  Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_file_name() {
+    assert_eq!(
+      file_name(Some("SSIS-001"), Some("标题: 副标题?"), None, "abc"),
+      "SSIS-001 标题 副标题.torrent"
+    );
+    assert_eq!(
+      file_name(Some("SSIS-001"), None, Some("ssis001"), "abc"),
+      "SSIS-001.torrent"
+    );
+    assert_eq!(file_name(None, None, Some("a/b"), "abc"), "a b.torrent");
+    assert_eq!(file_name(None, None, Some("///"), "abc"), "abc.torrent");
+  }
+
+  #[test]
+  fn test_with_hash_suffix() {
+    assert_eq!(
+      with_hash_suffix(
+        "SSIS-001.torrent",
+        "c12fe1c06bba254a9dc9f519b335aa7c1367a88a"
+      ),
+      "SSIS-001 c12fe1c0.torrent"
+    );
+    assert_eq!(with_hash_suffix("abc.torrent", "abc"), "abc abc.torrent");
+  }
+}
@@ -0,0 +1,259 @@
+use std::{cmp::Ordering, collections::BTreeMap, iter::Peekable, str::Chars};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, QBittorrentStateInner, TorrentContent, error::Result};
+use crate::scrape::get_movie_code;
+
+/// 视频文件扩展名
+pub const VIDEO_EXTENSIONS: &[&str] = &[
+  "mp4", "mkv", "avi", "wmv", "mov", "m4v", "ts", "m2ts", "flv", "rmvb", "webm", "mpg", "mpeg",
+];
+
+/// 文件重命名记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct FileRename {
+  pub old_path: String,
+  pub new_path: String,
+}
+
+/// 将路径拆分为目录、文件名（不含扩展名）和扩展名
+pub fn split_path(path: &str) -> (&str, &str, Option<&str>) {
+  let (dir, file_name) = match path.rfind('/') {
+    Some(pos) => (&path[..=pos], &path[pos + 1..]),
+    None => ("", path),
+  };
+
+  match file_name.rfind('.') {
+    Some(pos) if pos > 0 => (dir, &file_name[..pos], Some(&file_name[pos + 1..])),
+    _ => (dir, file_name, None),
+  }
+}
+
+/// 是否为视频文件
+pub fn is_video(path: &str) -> bool {
+  split_path(path)
+    .2
+    .is_some_and(|ext| VIDEO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// 取出连续的数字，去除开头的 0
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+  let mut digits = String::new();
+
+  while let Some(c) = chars.next_if(char::is_ascii_digit) {
+    digits.push(c);
+  }
+
+  digits.trim_start_matches('0').to_string()
+}
+
+/// 按自然顺序比较，连续的数字按数值比较，例如 `part2` 排在 `part10` 之前
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+  let mut a = a.chars().peekable();
+  let mut b = b.chars().peekable();
+
+  loop {
+    let ordering = match (a.peek().copied(), b.peek().copied()) {
+      (None, None) => return Ordering::Equal,
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+        let x = take_number(&mut a);
+        let y = take_number(&mut b);
+        x.len().cmp(&y.len()).then_with(|| x.cmp(&y))
+      }
+      (Some(x), Some(y)) => {
+        a.next();
+        b.next();
+        x.cmp(&y)
+      }
+    };
+
+    if ordering != Ordering::Equal {
+      return ordering;
+    }
+  }
+}
+
+/// 计算将视频文件重命名为番号的方案
+///
+/// 不下载的文件会被忽略；同一番号有多个文件时按原文件名的自然顺序排序，依次添加 `-CD1`、`-CD2` 后缀。
+fn plan_renames(contents: &[TorrentContent]) -> Vec<FileRename> {
+  let mut groups = BTreeMap::<String, Vec<&str>>::new();
+
+  for content in contents {
+    if content.priority == 0 || !is_video(&content.name) {
+      continue;
+    }
+
+    let stem = split_path(&content.name).1.to_string();
+
+    if let Some(code) = get_movie_code(&stem) {
+      groups.entry(code).or_default().push(&content.name);
+    }
+  }
+
+  let mut renames = vec![];
+
+  for (code, mut paths) in groups {
+    paths.sort_by(|a, b| natural_cmp(a, b));
+    let multi_part = paths.len() > 1;
+
+    for (i, path) in paths.into_iter().enumerate() {
+      let (dir, _, ext) = split_path(path);
+      let mut new_path = format!("{}{}", dir, code);
+
+      if multi_part {
+        new_path.push_str(&format!("-CD{}", i + 1));
+      }
+
+      if let Some(ext) = ext {
+        new_path.push('.');
+        new_path.push_str(ext);
+      }
+
+      if new_path != path {
+        renames.push(FileRename {
+          old_path: path.to_string(),
+          new_path,
+        });
+      }
+    }
+  }
+
+  renames
+}
+
+async fn rename_path(
+  state: &QBittorrentStateInner,
+  method_name: &str,
+  hash: &str,
+  old_path: &str,
+  new_path: &str,
+) -> Result<()> {
+  state
+    .post(
+      "torrents",
+      method_name,
+      &[("hash", hash), ("oldPath", old_path), ("newPath", new_path)],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 重命名种子中的文件
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_file(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+  old_path: String,
+  new_path: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  rename_path(&state, "renameFile", &hash, &old_path, &new_path).await
+}
+
+/// 重命名种子中的文件夹
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_folder(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+  old_path: String,
+  new_path: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  rename_path(&state, "renameFolder", &hash, &old_path, &new_path).await
+}
+
+/// 将种子中的视频文件重命名为番号，返回重命名的文件
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_files_to_movie_code(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<Vec<FileRename>> {
+  let state = state.lock().await?;
+  let contents = state.torrent_contents(&hash).await?;
+  let renames = plan_renames(&contents);
+
+  for rename in &renames {
+    rename_path(
+      &state,
+      "renameFile",
+      &hash,
+      &rename.old_path,
+      &rename.new_path,
+    )
+    .await?;
+  }
+
+  Ok(renames)
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn content(index: usize, name: &str, priority: i32) -> TorrentContent {
+    TorrentContent {
+      index,
+      name: name.to_string(),
+      size: 0,
+      progress: 0.0,
+      priority,
+      piece_range: vec![],
+      availability: 0.0,
+    }
+  }
+
+  #[test]
+  fn test_plan_renames() {
+    let contents = vec![
+      content(0, "abp-123/hhd800.com@ABP-123-B.mp4", 1),
+      content(1, "abp-123/hhd800.com@ABP-123-A.mp4", 1),
+      content(2, "abp-123/ABP-123.srt", 1),
+      content(3, "abp-123/sample.mp4", 0),
+      content(4, "SSIS-001.mkv", 1),
+      content(5, "IPX-456.mkv", 1),
+    ];
+
+    assert_eq!(
+      plan_renames(&contents),
+      vec![
+        FileRename {
+          old_path: "abp-123/hhd800.com@ABP-123-A.mp4".to_string(),
+          new_path: "abp-123/ABP-123-CD1.mp4".to_string(),
+        },
+        FileRename {
+          old_path: "abp-123/hhd800.com@ABP-123-B.mp4".to_string(),
+          new_path: "abp-123/ABP-123-CD2.mp4".to_string(),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_plan_renames_natural_order() {
+    let contents = (1..=11)
+      .rev()
+      .map(|i| content(i, &format!("SSIS-001/SSIS-001 part{}.mp4", i), 1))
+      .collect::<Vec<_>>();
+
+    let renames = plan_renames(&contents);
+    assert_eq!(renames.len(), 11);
+    assert_eq!(renames[1].old_path, "SSIS-001/SSIS-001 part2.mp4");
+    assert_eq!(renames[1].new_path, "SSIS-001/SSIS-001-CD2.mp4");
+    assert_eq!(renames[9].old_path, "SSIS-001/SSIS-001 part10.mp4");
+    assert_eq!(renames[9].new_path, "SSIS-001/SSIS-001-CD10.mp4");
+
+    assert_eq!(natural_cmp("part02", "part10"), Ordering::Less);
+    assert_eq!(natural_cmp("part2", "part02"), Ordering::Equal);
+  }
+}
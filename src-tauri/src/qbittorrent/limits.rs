@@ -0,0 +1,116 @@
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+/// 设置全局下载限速（字节/秒），0 表示不限速
+#[tauri::command]
+#[specta::specta]
+pub async fn set_global_download_limit(
+  state: State<'_, QBittorrentState>,
+  limit: i64,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "transfer",
+      "setDownloadLimit",
+      &[("limit", limit.to_string())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置全局上传限速（字节/秒），0 表示不限速
+#[tauri::command]
+#[specta::specta]
+pub async fn set_global_upload_limit(state: State<'_, QBittorrentState>, limit: i64) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "transfer",
+      "setUploadLimit",
+      &[("limit", limit.to_string())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 切换备用速度限制
+#[tauri::command]
+#[specta::specta]
+pub async fn toggle_speed_limits_mode(state: State<'_, QBittorrentState>) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("transfer", "toggleSpeedLimitsMode", &[] as &[(&str, &str)])
+    .await?;
+  Ok(())
+}
+
+/// 设置种子下载限速（字节/秒），0 表示不限速
+#[tauri::command]
+#[specta::specta]
+pub async fn set_torrent_download_limit(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  limit: i64,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setDownloadLimit",
+      &[("hashes", hashes.join("|")), ("limit", limit.to_string())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置种子上传限速（字节/秒），0 表示不限速
+#[tauri::command]
+#[specta::specta]
+pub async fn set_torrent_upload_limit(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  limit: i64,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setUploadLimit",
+      &[("hashes", hashes.join("|")), ("limit", limit.to_string())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置种子分享限制
+///
+/// -2 表示使用全局设置，-1 表示不限制；时间以分钟为单位。
+#[tauri::command]
+#[specta::specta]
+pub async fn set_share_limits(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  ratio_limit: f32,
+  seeding_time_limit: i64,
+  inactive_seeding_time_limit: i64,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setShareLimits",
+      &[
+        ("hashes", hashes.join("|")),
+        ("ratioLimit", ratio_limit.to_string()),
+        ("seedingTimeLimit", seeding_time_limit.to_string()),
+        (
+          "inactiveSeedingTimeLimit",
+          inactive_seeding_time_limit.to_string(),
+        ),
+      ],
+    )
+    .await?;
+  Ok(())
+}
@@ -0,0 +1,76 @@
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+async fn post_with_hashes(
+  state: &State<'_, QBittorrentState>,
+  method_name: &str,
+  hashes: &[String],
+  key: &str,
+  value: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      method_name,
+      &[("hashes", hashes.join("|").as_str()), (key, value.as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 移动种子文件到新位置
+///
+/// 移动时种子状态为 `Moving`，完成后会发送 `TorrentMoveFinished` 事件。
+#[tauri::command]
+#[specta::specta]
+pub async fn set_location(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  location: String,
+) -> Result<()> {
+  post_with_hashes(&state, "setLocation", &hashes, "location", location).await
+}
+
+/// 修改保存路径
+///
+/// 仅适用于未启用自动管理的种子，已下载的文件也会被移动。
+#[tauri::command]
+#[specta::specta]
+pub async fn set_save_path(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  path: String,
+) -> Result<()> {
+  post_with_hashes(&state, "setSavePath", &hashes, "path", path).await
+}
+
+/// 修改未完成下载的保存路径
+#[tauri::command]
+#[specta::specta]
+pub async fn set_download_path(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  path: String,
+) -> Result<()> {
+  post_with_hashes(&state, "setDownloadPath", &hashes, "path", path).await
+}
+
+/// 启用或禁用自动种子管理
+#[tauri::command]
+#[specta::specta]
+pub async fn set_auto_management(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  enable: bool,
+) -> Result<()> {
+  post_with_hashes(
+    &state,
+    "setAutoManagement",
+    &hashes,
+    "enable",
+    enable.to_string(),
+  )
+  .await
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+/// 日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(try_from = "i32")]
+pub enum LogLevel {
+  Normal,
+  Info,
+  Warning,
+  Critical,
+}
+
+impl TryFrom<i32> for LogLevel {
+  type Error = String;
+
+  fn try_from(value: i32) -> std::result::Result<Self, Self::Error> {
+    match value {
+      1 => Ok(Self::Normal),
+      2 => Ok(Self::Info),
+      4 => Ok(Self::Warning),
+      8 => Ok(Self::Critical),
+      _ => Err(format!("Unknown log type: {}", value)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LogEntry {
+  /// ID of the message
+  pub id: i64,
+  /// Seconds since epoch
+  pub timestamp: i64,
+  /// Type of the message
+  #[serde(alias = "type")]
+  pub level: LogLevel,
+  /// Text of the message
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PeerLogEntry {
+  /// ID of the peer
+  pub id: i64,
+  /// Seconds since epoch
+  pub timestamp: i64,
+  /// IP of the peer
+  pub ip: String,
+  /// Whether or not the peer was blocked
+  pub blocked: bool,
+  /// Reason of the block
+  pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BuildInfo {
+  /// Qt version
+  pub qt: String,
+  /// libtorrent version
+  pub libtorrent: String,
+  /// Boost version
+  pub boost: String,
+  /// OpenSSL version
+  pub openssl: String,
+  /// Application bitness (e.g. 64-bit)
+  pub bitness: i32,
+}
+
+/// 获取日志，只返回 ID 大于 `last_known_id` 的日志
+#[tauri::command]
+#[specta::specta]
+pub async fn get_main_log(
+  state: State<'_, QBittorrentState>,
+  last_known_id: Option<i64>,
+) -> Result<Vec<LogEntry>> {
+  let last_known_id = last_known_id.unwrap_or(-1).to_string();
+  let state = state.lock().await?;
+  state
+    .get(
+      "log",
+      "main",
+      Some(&[("last_known_id", last_known_id.as_str())]),
+    )
+    .await
+}
+
+/// 获取用户日志，只返回 ID 大于 `last_known_id` 的日志
+#[tauri::command]
+#[specta::specta]
+pub async fn get_peer_log(
+  state: State<'_, QBittorrentState>,
+  last_known_id: Option<i64>,
+) -> Result<Vec<PeerLogEntry>> {
+  let last_known_id = last_known_id.unwrap_or(-1).to_string();
+  let state = state.lock().await?;
+  state
+    .get(
+      "log",
+      "peers",
+      Some(&[("last_known_id", last_known_id.as_str())]),
+    )
+    .await
+}
+
+/// 获取 qBittorrent 版本
+#[tauri::command]
+#[specta::specta]
+pub async fn get_app_version(state: State<'_, QBittorrentState>) -> Result<String> {
+  let state = state.lock().await?;
+  state.get_text("app", "version").await
+}
+
+/// 获取 WebUI API 版本
+#[tauri::command]
+#[specta::specta]
+pub async fn get_webapi_version(state: State<'_, QBittorrentState>) -> Result<String> {
+  let state = state.lock().await?;
+  state.get_text("app", "webapiVersion").await
+}
+
+/// 获取编译信息
+#[tauri::command]
+#[specta::specta]
+pub async fn get_build_info(state: State<'_, QBittorrentState>) -> Result<BuildInfo> {
+  let state = state.lock().await?;
+  state.get("app", "buildInfo", None::<&()>).await
+}
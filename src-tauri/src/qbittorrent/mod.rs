@@ -0,0 +1,921 @@
+mod categories;
+mod convert;
+mod error;
+mod events;
+mod export;
+mod files;
+mod limits;
+mod location;
+mod logs;
+mod options;
+mod preferences;
+mod profiles;
+mod properties;
+mod queue;
+mod rss;
+mod search;
+mod select;
+mod sync;
+mod tags;
+mod toggles;
+mod trackers;
+
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+  sync::Arc,
+};
+
+use error::Result;
+use log::{debug, warn};
+use properties::PeersData;
+use reqwest::{Client, Proxy, RequestBuilder, Response, StatusCode, multipart};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use sync::{SyncChange, SyncData};
+use tauri::{
+  AppHandle, State,
+  async_runtime::{JoinHandle, Mutex},
+};
+use tokio::sync::OwnedMutexGuard;
+use url::Url;
+
+pub use categories::{
+  Category, create_category, edit_category, get_categories, remove_categories, set_category,
+};
+pub use convert::{convert_magnet_to_torrent, get_torrent_caches, set_torrent_caches};
+pub use error::QBittorrentError;
+pub use events::{
+  ServerStateChanged, TorrentAdded, TorrentCompleted, TorrentMoveFinished, TorrentRemoved,
+  TorrentStateChanged, TorrentUpdated, start_sync, stop_sync,
+};
+pub use export::export_torrents;
+pub use files::{rename_file, rename_files_to_movie_code, rename_folder};
+pub use limits::{
+  set_global_download_limit, set_global_upload_limit, set_share_limits, set_torrent_download_limit,
+  set_torrent_upload_limit, toggle_speed_limits_mode,
+};
+pub use location::{set_auto_management, set_download_path, set_location, set_save_path};
+pub use logs::{get_app_version, get_build_info, get_main_log, get_peer_log, get_webapi_version};
+pub use options::AddTorrentOptions;
+pub use preferences::{get_preferences, set_preferences};
+pub use profiles::{
+  compare_torrents, get_active_profile, get_profiles, remove_profile, save_profile, switch_profile,
+  transfer_torrents,
+};
+pub use properties::{
+  get_piece_states, get_torrent_peers, get_torrent_properties, get_torrent_trackers,
+  get_torrent_webseeds,
+};
+pub use queue::{
+  bottom_priority, decrease_priority, increase_priority, reorder_queue, top_priority,
+};
+pub use rss::{
+  add_rss_feed, add_rss_folder, get_rss_items, get_rss_matching_articles, get_rss_rules,
+  mark_rss_as_read, move_rss_item, refresh_rss_item, remove_rss_item, remove_rss_rule,
+  rename_rss_rule, set_movie_code_rss_rule, set_rss_rule,
+};
+pub use search::{
+  delete_search, enable_search_plugin, get_search_plugins, get_search_results, get_search_status,
+  install_search_plugin, search_movie_code, start_search, stop_search,
+};
+pub use select::{auto_select_files, get_select_rules, set_select_rules};
+pub use sync::get_main_data_snapshot;
+pub use tags::{add_tags, create_tags, delete_tags, get_tags, remove_tags};
+pub use toggles::{
+  set_force_start, set_super_seeding, toggle_first_last_piece_prio, toggle_sequential_download,
+};
+pub use trackers::{
+  add_trackers, apply_tracker_list, edit_tracker, get_tracker_list, remove_trackers,
+  set_tracker_list,
+};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TorrentInfo {
+  /// Time (Unix Epoch) when the torrent was added to the client
+  added_on: Option<i64>,
+  /// Amount of data left to download (bytes)
+  amount_left: Option<i64>,
+  /// Whether this torrent is managed by Automatic Torrent Management
+  auto_tmm: Option<bool>,
+  /// Percentage of file pieces currently available
+  availability: Option<f32>,
+  /// Category of the torrent
+  category: Option<String>,
+  /// Amount of transfer data completed (bytes)
+  completed: Option<i64>,
+  /// Time (Unix Epoch) when the torrent completed
+  completion_on: Option<i64>,
+  /// Torrent download speed limit (bytes/s). -1 if unlimited.
+  dl_limit: Option<i64>,
+  /// Torrent download speed (bytes/s)
+  dlspeed: Option<i64>,
+  /// Amount of data downloaded
+  downloaded: Option<i64>,
+  /// Amount of data downloaded this session
+  downloaded_session: Option<i64>,
+  /// Torrent ETA (seconds)
+  eta: Option<i64>,
+  /// True if first last piece are prioritized
+  f_l_piece_prio: Option<bool>,
+  /// True if force start is enabled for this torrent
+  force_start: Option<bool>,
+  /// Torrent hash
+  hash: Option<String>,
+  infohash_v1: Option<String>,
+  infohash_v2: Option<String>,
+  /// Last time (Unix Epoch) when a chunk was downloaded/uploaded
+  last_activity: Option<i64>,
+  /// Magnet URI corresponding to this torrent
+  magnet_uri: Option<String>,
+  /// Maximum share ratio until torrent is stopped from seeding/uploading
+  max_ratio: Option<f32>,
+  /// Maximum seeding time (seconds) until torrent is stopped from seeding
+  max_seeding_time: Option<i64>,
+  /// Torrent name
+  name: Option<String>,
+  /// Number of seeds in the swarm
+  num_complete: Option<i64>,
+  /// Number of leechers in the swarm
+  num_incomplete: Option<i64>,
+  /// Number of leechers connected to
+  num_leechs: Option<i64>,
+  /// Number of seeds connected to
+  num_seeds: Option<i64>,
+  /// Torrent priority. Returns -1 if queuing is disabled or torrent is in seed mode
+  priority: Option<i64>,
+  /// Torrent progress (percentage/100)
+  progress: Option<f32>,
+  /// Torrent share ratio. Max ratio value: 9999.
+  ratio: Option<f32>,
+  /// TODO (what is different from max_ratio?)
+  ratio_limit: Option<f32>,
+  /// Path where this torrent's data is stored
+  save_path: Option<String>,
+  /// TODO (what is different from max_seeding_time?)
+  seeding_time_limit: Option<i64>,
+  /// Time (Unix Epoch) when this torrent was last seen complete
+  seen_complete: Option<i64>,
+  /// True if sequential download is enabled
+  seq_dl: Option<bool>,
+  /// Total size (bytes) of files selected for download
+  size: Option<i64>,
+  /// Torrent state
+  state: Option<TorrentState>,
+  /// True if super seeding is enabled
+  super_seeding: Option<bool>,
+  /// Tag list of the torrent
+  #[serde(default, deserialize_with = "tags::deserialize_tags")]
+  tags: Option<Vec<String>>,
+  /// Total active time (seconds)
+  time_active: Option<i64>,
+  /// Total size (bytes) of all file in this torrent (including unselected ones)
+  total_size: Option<i64>,
+  /// The first tracker with working status. Returns empty string if no tracker is working.
+  tracker: Option<String>,
+  /// Torrent upload speed limit (bytes/s). -1 if unlimited.
+  up_limit: Option<i64>,
+  /// Amount of data uploaded
+  uploaded: Option<i64>,
+  /// Amount of data uploaded this session
+  uploaded_session: Option<i64>,
+  /// Torrent upload speed (bytes/s)
+  upspeed: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TorrentContent {
+  /// File index
+  index: usize,
+  /// File name (including relative path)
+  name: String,
+  /// File size (bytes)
+  size: i64,
+  /// File progress (percentage/100)
+  progress: f32,
+  /// File priority
+  priority: i32,
+  /// True if file is seeding/complete
+  //is_seed: bool,
+  /// The first number is the starting piece index and the second number is the ending piece index (inclusive)
+  piece_range: Vec<usize>,
+  /// Percentage of file pieces currently available
+  availability: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum TorrentState {
+  Error,
+  MissingFiles,
+  Uploading,
+  /// qBittorrent 5 之前为 `pausedUP`
+  #[serde(alias = "pausedUP")]
+  StoppedUP,
+  QueuedUP,
+  StalledUP,
+  CheckingUP,
+  ForcedUP,
+  Allocating,
+  Downloading,
+  MetaDL,
+  /// qBittorrent 5 之前为 `pausedDL`
+  #[serde(alias = "pausedDL")]
+  StoppedDL,
+  QueuedDL,
+  StalledDL,
+  CheckingDL,
+  ForcedDL,
+  CheckingResumeData,
+  Moving,
+  /// 无法识别的状态
+  #[serde(other)]
+  Unknown,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+pub struct ServerState {
+  pub alltime_dl: Option<i64>,
+  pub alltime_ul: Option<i64>,
+  pub average_time_queue: Option<i64>,
+  /// Connection status
+  pub connection_status: Option<ConnectionStatus>,
+  /// DHT nodes connected to
+  pub dht_nodes: Option<u32>,
+  /// Data downloaded this session (bytes)
+  pub dl_info_data: Option<i64>,
+  /// Global download rate (bytes/s)
+  pub dl_info_speed: Option<i64>,
+  /// Download rate limit (bytes/s)
+  pub dl_rate_limit: Option<i64>,
+  pub free_space_on_disk: Option<i64>,
+  pub global_ratio: Option<String>,
+  pub queued_io_jobs: Option<u32>,
+  /// True if torrent queueing is enabled
+  pub queueing: Option<bool>,
+  pub read_cache_hits: Option<String>,
+  pub read_cache_overload: Option<String>,
+  /// Transfer list refresh interval (milliseconds)
+  pub refresh_interval: Option<i64>,
+  pub total_buffers_size: Option<i64>,
+  pub total_peer_connections: Option<u32>,
+  pub total_queued_size: Option<i64>,
+  pub total_wasted_session: Option<i64>,
+  /// Data uploaded this session (bytes)
+  pub up_info_data: Option<i64>,
+  /// Global upload rate (bytes/s)
+  pub up_info_speed: Option<i64>,
+  /// Upload rate limit (bytes/s)
+  pub up_rate_limit: Option<i64>,
+  /// True if alternative speed limits are enabled
+  pub use_alt_speed_limits: Option<bool>,
+  pub use_subcategories: Option<bool>,
+  pub write_cache_overload: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionStatus {
+  Connected,
+  Firewalled,
+  Disconnected,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MainData {
+  /// Whether the response contains all the data or partial data
+  #[serde(default)]
+  pub full_update: bool,
+  /// Response ID
+  pub rid: u32,
+  /// Global transfer info
+  pub server_state: Option<ServerState>,
+  /// Property: torrent hash, value: same as torrent list
+  pub torrents: Option<HashMap<String, TorrentInfo>>,
+  /// List of hashes of torrents removed since last request
+  pub torrents_removed: Option<Vec<String>>,
+  /// Info for categories added since last request
+  pub categories: Option<HashMap<String, Category>>,
+  /// List of categories removed since last request
+  pub categories_removed: Option<Vec<String>>,
+  /// List of tags added since last request
+  pub tags: Option<Vec<String>>,
+  /// List of tags removed since last request
+  pub tags_removed: Option<Vec<String>>,
+}
+
+/// 登录凭据
+struct Credentials {
+  username: String,
+  password: String,
+}
+
+#[derive(Default)]
+pub struct QBittorrentStateInner {
+  url: Option<Url>,
+  /// HTTP 客户端
+  client: Option<Client>,
+  /// 用于会话过期时重新登录
+  credentials: Option<Credentials>,
+  /// 合并后的同步数据
+  sync: SyncData,
+  /// 各种子合并后的用户列表
+  peers: HashMap<String, PeersData>,
+  /// 登录时获取的 qBittorrent 版本
+  version: Option<(u32, u32, u32)>,
+}
+
+/// 解析 `app/version` 返回的版本号，如 `v4.6.7`
+fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+  let mut parts = text
+    .trim()
+    .trim_start_matches('v')
+    .split(['.', '-'])
+    .map(|part| part.parse::<u32>().ok());
+  let major = parts.next()??;
+  let minor = parts.next().flatten().unwrap_or_default();
+  let patch = parts.next().flatten().unwrap_or_default();
+  Some((major, minor, patch))
+}
+
+impl QBittorrentStateInner {
+  fn get_url(&self, api_name: &str, method_name: &str) -> Result<Url> {
+    let url = self.url.as_ref().ok_or(QBittorrentError::NotInitialized)?;
+    let path = format!("/api/v2/{}/{}", api_name, method_name);
+    Ok(url.join(&path)?)
+  }
+
+  fn client(&self) -> Result<&Client> {
+    self.client.as_ref().ok_or(QBittorrentError::NotInitialized)
+  }
+
+  async fn get<F, T>(&self, api_name: &str, method_name: &str, query: Option<&F>) -> Result<T>
+  where
+    F: Serialize + ?Sized,
+    T: DeserializeOwned,
+  {
+    let mut url = self.get_url(api_name, method_name)?;
+
+    if let Some(query) = query {
+      let query = serde_urlencoded::to_string(query)?;
+      url.set_query(Some(&query));
+    }
+
+    let res = self.send(|client| client.get(url.clone())).await?;
+
+    #[cfg(debug_assertions)]
+    {
+      let text = res.text().await?;
+      return match serde_json::from_str(&text) {
+        Ok(data) => Ok(data),
+        Err(e) => {
+          log::debug!("Error: {}", e);
+          log::debug!("Text: {}", text);
+          Err(e.into())
+        }
+      };
+    }
+
+    #[cfg(not(debug_assertions))]
+    Ok(res.json().await?)
+  }
+
+  /// 以字节形式获取响应
+  async fn get_bytes<F: Serialize + ?Sized>(
+    &self,
+    api_name: &str,
+    method_name: &str,
+    query: &F,
+  ) -> Result<Vec<u8>> {
+    let mut url = self.get_url(api_name, method_name)?;
+    url.set_query(Some(&serde_urlencoded::to_string(query)?));
+    let res = self.send(|client| client.get(url.clone())).await?;
+    Ok(res.bytes().await?.to_vec())
+  }
+
+  /// 以文本形式获取响应
+  async fn get_text(&self, api_name: &str, method_name: &str) -> Result<String> {
+    let url = self.get_url(api_name, method_name)?;
+    let res = self.send(|client| client.get(url.clone())).await?;
+    Ok(res.text().await?)
+  }
+
+  async fn post<F: Serialize + ?Sized>(
+    &self,
+    api_name: &str,
+    method_name: &str,
+    body: &F,
+  ) -> Result<String> {
+    let url = self.get_url(api_name, method_name)?;
+    let res = self
+      .send(|client| client.post(url.clone()).form(body))
+      .await?;
+    Ok(res.text().await?)
+  }
+
+  /// 发送请求，会话过期时重新登录并重试一次
+  async fn send<B>(&self, build: B) -> Result<Response>
+  where
+    B: Fn(&Client) -> RequestBuilder,
+  {
+    let client = self.client()?;
+    let res = build(client).send().await?;
+
+    match QBittorrentError::check(res).await {
+      Err(QBittorrentError::Unauthorized) => {
+        let credentials = self
+          .credentials
+          .as_ref()
+          .ok_or(QBittorrentError::Unauthorized)?;
+
+        debug!("Session expired, logging in again");
+
+        if !self.auth_login(credentials).await? {
+          return Err(QBittorrentError::Unauthorized);
+        }
+
+        let res = build(client).send().await?;
+        QBittorrentError::check(res).await
+      }
+      result => result,
+    }
+  }
+
+  async fn auth_login(&self, credentials: &Credentials) -> Result<bool> {
+    let res = self
+      .client()?
+      .post(self.get_url("auth", "login")?)
+      .form(&[
+        ("username", credentials.username.as_str()),
+        ("password", credentials.password.as_str()),
+      ])
+      .send()
+      .await?;
+
+    // 登录失败次数过多时返回 403
+    if res.status() == StatusCode::FORBIDDEN {
+      return Err(QBittorrentError::Banned);
+    }
+
+    let text = QBittorrentError::check(res).await?.text().await?;
+    Ok(text == "Ok.")
+  }
+
+  /// 是否为 qBittorrent 5 之前的版本，未知版本时视为新版本
+  fn is_legacy(&self) -> bool {
+    self.version.is_some_and(|(major, _, _)| major < 5)
+  }
+
+  /// 设置服务器地址与代理，并清空之前的会话
+  fn configure(&mut self, url: &str, proxy: Option<String>) -> Result<()> {
+    self.url = Some(Url::parse(url)?);
+    self.credentials = None;
+    self.version = None;
+    self.sync = SyncData::default();
+    self.peers.clear();
+
+    let mut builder = Client::builder().cookie_store(true);
+
+    if let Some(proxy) = proxy {
+      if proxy.is_empty() {
+        builder = builder.no_proxy();
+      } else {
+        builder = builder.proxy(Proxy::all(proxy)?);
+      }
+    }
+
+    self.client = Some(builder.build()?);
+    Ok(())
+  }
+
+  async fn login(&mut self, credentials: Credentials, remember: bool) -> Result<bool> {
+    let ok = self.auth_login(&credentials).await?;
+    self.credentials = if ok && remember {
+      Some(credentials)
+    } else {
+      None
+    };
+
+    if ok {
+      self.version = match self.get_text("app", "version").await {
+        Ok(text) => parse_version(&text),
+        Err(e) => {
+          warn!("Failed to get qBittorrent version: {}", e);
+          None
+        }
+      };
+      debug!("qBittorrent version: {:?}", self.version);
+    }
+
+    Ok(ok)
+  }
+
+  /// 以链接添加种子，多个链接以换行分隔
+  async fn add_torrent_urls(&self, urls: &str, options: &AddTorrentOptions) -> Result<()> {
+    let mut params = options.to_params();
+    params.push(("urls", urls.to_string()));
+    let result = self.post("torrents", "add", &params).await?;
+    check_added(&result)
+  }
+
+  /// 以种子文件添加种子，`files` 为文件名与文件内容
+  async fn add_torrent_files(
+    &self,
+    files: &[(String, Vec<u8>)],
+    options: &AddTorrentOptions,
+  ) -> Result<()> {
+    let url = self.get_url("torrents", "add")?;
+    let params = options.to_params();
+    let res = self
+      .send(|client| {
+        let mut form = multipart::Form::new();
+
+        for (key, value) in &params {
+          form = form.text(*key, value.clone());
+        }
+
+        for (file_name, data) in files {
+          let part = multipart::Part::bytes(data.clone()).file_name(file_name.clone());
+          form = form.part("torrents", part);
+        }
+
+        client.post(url.clone()).multipart(form)
+      })
+      .await?;
+    check_added(&res.text().await?)
+  }
+
+  /// 获取种子中的文件
+  async fn torrent_contents(&self, hash: &str) -> Result<Vec<TorrentContent>> {
+    self.get("torrents", "files", Some(&[("hash", hash)])).await
+  }
+
+  /// 设置文件优先级
+  async fn file_priority(&self, hash: &str, indexes: &[usize], priority: i32) -> Result<()> {
+    let id = indexes
+      .iter()
+      .map(|i| i.to_string())
+      .collect::<Vec<String>>()
+      .join("|");
+
+    self
+      .post(
+        "torrents",
+        "filePrio",
+        &[
+          ("hash", hash),
+          ("id", id.as_str()),
+          ("priority", priority.to_string().as_str()),
+        ],
+      )
+      .await?;
+    Ok(())
+  }
+
+  /// 获取种子列表，`hashes` 为空时获取全部种子
+  async fn torrents_info(&self, hashes: Option<&[String]>) -> Result<Vec<TorrentInfo>> {
+    match hashes {
+      Some(hashes) => {
+        self
+          .get(
+            "torrents",
+            "info",
+            Some(&[("hashes", hashes.join("|").as_str())]),
+          )
+          .await
+      }
+      None => self.get("torrents", "info", None::<&()>).await,
+    }
+  }
+
+  /// 获取所有种子的哈希，包括 v1 与 v2 info hash
+  pub async fn torrent_hashes(&self) -> Result<HashSet<String>> {
+    let torrents = self.torrents_info(None).await?;
+    Ok(
+      torrents
+        .into_iter()
+        .flat_map(|info| [info.hash, info.infohash_v1, info.infohash_v2])
+        .flatten()
+        .filter(|hash| !hash.is_empty())
+        .map(|hash| hash.to_lowercase())
+        .collect(),
+    )
+  }
+
+  /// 获取主要数据的增量并合并
+  async fn sync_main_data(&mut self) -> Result<Vec<SyncChange>> {
+    let data: MainData = self
+      .get(
+        "sync",
+        "maindata",
+        Some(&[("rid", self.sync.rid.to_string().as_str())]),
+      )
+      .await?;
+    let changes = self.sync.apply(data);
+
+    // 删除的种子不再需要保留 peers 的增量数据
+    for change in &changes {
+      if let SyncChange::TorrentRemoved(hash) = change {
+        self.peers.remove(hash);
+      }
+    }
+
+    Ok(changes)
+  }
+
+  /// 清空合并的数据，返回之前的种子哈希
+  fn reset_sync(&mut self) -> Vec<String> {
+    let hashes = self.sync.torrents.keys().cloned().collect();
+    self.sync = SyncData::default();
+    self.peers.clear();
+    hashes
+  }
+}
+
+/// 未配置服务器时使用的配置 ID
+const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Default)]
+pub struct QBittorrentState {
+  /// 当前使用的服务器配置 ID
+  active: Mutex<Option<String>>,
+  /// 各服务器配置的连接
+  servers: Mutex<HashMap<String, Arc<Mutex<QBittorrentStateInner>>>>,
+  /// 后台同步任务
+  sync_task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl QBittorrentState {
+  /// 当前使用的服务器配置 ID，启动后未切换过时使用保存的配置
+  async fn active_profile(&self) -> String {
+    let mut active = self.active.lock().await;
+
+    if active.is_none() {
+      *active = profiles::saved_active_profile();
+    }
+
+    active
+      .clone()
+      .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+  }
+
+  /// 锁定当前服务器的连接，尚未连接时按保存的配置连接
+  pub async fn lock(&self) -> Result<OwnedMutexGuard<QBittorrentStateInner>> {
+    let profile_id = self.active_profile().await;
+    profiles::connect(self, &profile_id).await
+  }
+
+  /// 锁定指定服务器配置的连接
+  pub async fn lock_profile(&self, profile_id: &str) -> OwnedMutexGuard<QBittorrentStateInner> {
+    let server = {
+      let mut servers = self.servers.lock().await;
+      servers.entry(profile_id.to_string()).or_default().clone()
+    };
+    server.lock_owned().await
+  }
+}
+
+/// 设置不使用服务器配置时的 URL，并切换为不使用服务器配置
+#[tauri::command]
+#[specta::specta]
+pub async fn initialize(
+  app: AppHandle,
+  state: State<'_, QBittorrentState>,
+  url: String,
+  proxy: Option<String>,
+) -> Result<()> {
+  profiles::activate(&app, &state, DEFAULT_PROFILE).await?;
+  let mut state = state.lock_profile(DEFAULT_PROFILE).await;
+  state.configure(&url, proxy)
+}
+
+/// 登录不使用服务器配置时的连接
+///
+/// `remember` 默认为 `true`，此时会在内存中保存凭据，以便会话过期后自动重新登录。
+#[tauri::command]
+#[specta::specta]
+pub async fn login(
+  state: State<'_, QBittorrentState>,
+  username: String,
+  password: String,
+  remember: Option<bool>,
+) -> Result<bool> {
+  let mut state = state.lock_profile(DEFAULT_PROFILE).await;
+  state
+    .login(Credentials { username, password }, remember.unwrap_or(true))
+    .await
+}
+
+/// 获取主要数据
+///
+/// 与后台同步共用合并后的数据，同步到的变化同样以事件发送，返回完整数据。
+#[tauri::command]
+#[specta::specta]
+pub async fn get_main_data(app: AppHandle, state: State<'_, QBittorrentState>) -> Result<MainData> {
+  let mut state = state.lock().await?;
+  let changes = state.sync_main_data().await?;
+  events::emit_changes(&app, &state, changes);
+  Ok(state.sync.snapshot())
+}
+
+/// 获取种子内容
+#[tauri::command]
+#[specta::specta]
+pub async fn get_torrent_contents(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<Vec<TorrentContent>> {
+  let state = state.lock().await?;
+  state.torrent_contents(&hash).await
+}
+
+/// 检查 `torrents/add` 的结果，所有种子都添加失败时返回 `Fails.`
+fn check_added(result: &str) -> Result<()> {
+  if result == "Fails." {
+    Err(QBittorrentError::InvalidTorrent)
+  } else {
+    Ok(())
+  }
+}
+
+/// 添加链接
+///
+/// 未指定 `options` 时创建根目录。
+#[tauri::command]
+#[specta::specta]
+pub async fn add_urls(
+  state: State<'_, QBittorrentState>,
+  urls: String,
+  options: Option<AddTorrentOptions>,
+) -> Result<()> {
+  let options = options.unwrap_or_else(|| AddTorrentOptions {
+    root_folder: Some(true),
+    ..Default::default()
+  });
+
+  let state = state.lock().await?;
+  state.add_torrent_urls(&urls, &options).await
+}
+
+/// 添加文件
+///
+/// 未指定 `options` 时以停止状态添加，并创建根目录。
+#[tauri::command]
+#[specta::specta]
+pub async fn add_files(
+  state: State<'_, QBittorrentState>,
+  paths: Vec<String>,
+  options: Option<AddTorrentOptions>,
+) -> Result<()> {
+  let options = options.unwrap_or_else(|| AddTorrentOptions {
+    stopped: Some(true),
+    root_folder: Some(true),
+    ..Default::default()
+  });
+
+  // 先读取文件，以便重新登录后可以再次构造请求
+  let mut files = vec![];
+
+  for path in paths {
+    let file_name = Path::new(&path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    let data = tokio::fs::read(&path).await?;
+    files.push((file_name, data));
+  }
+
+  let state = state.lock().await?;
+  state.add_torrent_files(&files, &options).await
+}
+
+/// 开始
+///
+/// qBittorrent 5 之前使用 `torrents/resume`。
+#[tauri::command]
+#[specta::specta]
+pub async fn start(state: State<'_, QBittorrentState>, hashes: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  let method_name = if state.is_legacy() { "resume" } else { "start" };
+  state
+    .post(
+      "torrents",
+      method_name,
+      &[("hashes", hashes.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 停止
+///
+/// qBittorrent 5 之前使用 `torrents/pause`。
+#[tauri::command]
+#[specta::specta]
+pub async fn stop(state: State<'_, QBittorrentState>, hashes: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  let method_name = if state.is_legacy() { "pause" } else { "stop" };
+  state
+    .post(
+      "torrents",
+      method_name,
+      &[("hashes", hashes.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 重新校验
+#[tauri::command]
+#[specta::specta]
+pub async fn recheck(state: State<'_, QBittorrentState>, hashes: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "recheck",
+      &[("hashes", hashes.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除
+#[tauri::command]
+#[specta::specta]
+pub async fn delete(state: State<'_, QBittorrentState>, hashes: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "delete",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("deleteFiles", "true"),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 重命名
+#[tauri::command]
+#[specta::specta]
+pub async fn rename(state: State<'_, QBittorrentState>, hash: String, name: String) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "rename",
+      &[("hash", hash.as_str()), ("name", name.as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置文件优先级
+#[tauri::command]
+#[specta::specta]
+pub async fn set_file_priority(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+  indexes: Vec<usize>,
+  priority: i32,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state.file_priority(&hash, &indexes, priority).await
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_parse_version() {
+    assert_eq!(parse_version("v4.6.7"), Some((4, 6, 7)));
+    assert_eq!(parse_version("v5.0.0beta1"), Some((5, 0, 0)));
+    assert_eq!(parse_version("v5.1"), Some((5, 1, 0)));
+    assert_eq!(parse_version(""), None);
+  }
+
+  #[test]
+  fn test_torrent_state() {
+    let states: Vec<TorrentState> =
+      serde_json::from_str(r#"["pausedUP", "stoppedDL", "pausedDL", "somethingNew"]"#).unwrap();
+    assert_eq!(
+      states,
+      vec![
+        TorrentState::StoppedUP,
+        TorrentState::StoppedDL,
+        TorrentState::StoppedDL,
+        TorrentState::Unknown,
+      ]
+    );
+  }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+
+/// 内容布局
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+pub enum ContentLayout {
+  Original,
+  Subfolder,
+  NoSubfolder,
+}
+
+impl ContentLayout {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Original => "Original",
+      Self::Subfolder => "Subfolder",
+      Self::NoSubfolder => "NoSubfolder",
+    }
+  }
+}
+
+/// 自动停止条件
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+pub enum StopCondition {
+  None,
+  MetadataReceived,
+  FilesChecked,
+}
+
+impl StopCondition {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::None => "None",
+      Self::MetadataReceived => "MetadataReceived",
+      Self::FilesChecked => "FilesChecked",
+    }
+  }
+}
+
+/// 添加种子的选项
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+pub struct AddTorrentOptions {
+  /// Download folder
+  pub save_path: Option<String>,
+  /// Incomplete download folder
+  pub download_path: Option<String>,
+  /// Whether the incomplete download folder is used
+  pub use_download_path: Option<bool>,
+  /// Cookie sent to download the .torrent file
+  pub cookie: Option<String>,
+  /// Category for the torrent
+  pub category: Option<String>,
+  /// Tags for the torrent
+  pub tags: Option<Vec<String>>,
+  /// Skip hash checking
+  pub skip_checking: Option<bool>,
+  /// Add torrents in the stopped (paused) state
+  pub stopped: Option<bool>,
+  /// Create the root folder. Superseded by `content_layout` since qBittorrent 4.3.2.
+  pub root_folder: Option<bool>,
+  /// Content layout
+  pub content_layout: Option<ContentLayout>,
+  /// Rename torrent
+  pub rename: Option<String>,
+  /// Set torrent upload speed limit (bytes/second)
+  pub up_limit: Option<i64>,
+  /// Set torrent download speed limit (bytes/second)
+  pub dl_limit: Option<i64>,
+  /// Set torrent share ratio limit
+  pub ratio_limit: Option<f32>,
+  /// Set torrent seeding time limit (minutes)
+  pub seeding_time_limit: Option<i64>,
+  /// Set torrent inactive seeding time limit (minutes)
+  pub inactive_seeding_time_limit: Option<i64>,
+  /// Whether Automatic Torrent Management should be used
+  pub auto_tmm: Option<bool>,
+  /// Enable sequential download
+  pub sequential_download: Option<bool>,
+  /// Prioritize download first last piece
+  pub first_last_piece_prio: Option<bool>,
+  /// Stop condition
+  pub stop_condition: Option<StopCondition>,
+  /// Add torrents to the top of the queue
+  pub add_to_top_of_queue: Option<bool>,
+}
+
+impl AddTorrentOptions {
+  /// 转换为 `torrents/add` 的参数
+  pub fn to_params(&self) -> Vec<(&'static str, String)> {
+    fn push<T: ToString>(
+      params: &mut Vec<(&'static str, String)>,
+      key: &'static str,
+      value: &Option<T>,
+    ) {
+      if let Some(value) = value {
+        params.push((key, value.to_string()));
+      }
+    }
+
+    let mut params = vec![];
+    push(&mut params, "savepath", &self.save_path);
+    push(&mut params, "downloadPath", &self.download_path);
+    push(&mut params, "useDownloadPath", &self.use_download_path);
+    push(&mut params, "cookie", &self.cookie);
+    push(&mut params, "category", &self.category);
+    push(
+      &mut params,
+      "tags",
+      &self.tags.as_ref().map(|tags| tags.join(",")),
+    );
+    push(&mut params, "skip_checking", &self.skip_checking);
+    // qBittorrent 5 使用 stopped，之前的版本使用 paused
+    push(&mut params, "stopped", &self.stopped);
+    push(&mut params, "paused", &self.stopped);
+    push(&mut params, "root_folder", &self.root_folder);
+    push(
+      &mut params,
+      "contentLayout",
+      &self.content_layout.map(|layout| layout.as_str()),
+    );
+    push(&mut params, "rename", &self.rename);
+    push(&mut params, "upLimit", &self.up_limit);
+    push(&mut params, "dlLimit", &self.dl_limit);
+    push(&mut params, "ratioLimit", &self.ratio_limit);
+    push(&mut params, "seedingTimeLimit", &self.seeding_time_limit);
+    push(
+      &mut params,
+      "inactiveSeedingTimeLimit",
+      &self.inactive_seeding_time_limit,
+    );
+    push(&mut params, "autoTMM", &self.auto_tmm);
+    push(&mut params, "sequentialDownload", &self.sequential_download);
+    push(
+      &mut params,
+      "firstLastPiecePrio",
+      &self.first_last_piece_prio,
+    );
+    push(
+      &mut params,
+      "stopCondition",
+      &self.stop_condition.map(|condition| condition.as_str()),
+    );
+    push(&mut params, "addToTopOfQueue", &self.add_to_top_of_queue);
+    params
+  }
+}
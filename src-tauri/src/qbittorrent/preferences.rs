@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+/// 应用程序设置
+///
+/// 所有字段均可为空，修改时只发送有值的字段。
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+pub struct Preferences {
+  /// Default save path for torrents, separated by slashes
+  pub save_path: Option<String>,
+  /// True if folder for incomplete torrents is enabled
+  pub temp_path_enabled: Option<bool>,
+  /// Path for incomplete torrents, separated by slashes
+  pub temp_path: Option<String>,
+  /// Path to directory to copy .torrent files to
+  pub export_dir: Option<String>,
+  /// Path to directory to copy .torrent files of completed downloads to
+  pub export_dir_fin: Option<String>,
+  /// True if Automatic Torrent Management is enabled by default
+  pub auto_tmm_enabled: Option<bool>,
+  /// True if torrent should be relocated when its Category's save path changes
+  pub torrent_changed_tmm_enabled: Option<bool>,
+  /// True if torrent should be relocated when the default save path changes
+  pub save_path_changed_tmm_enabled: Option<bool>,
+  /// True if torrent should be relocated when its Category changes
+  pub category_changed_tmm_enabled: Option<bool>,
+  /// True if ".!qB" should be appended to incomplete files
+  pub incomplete_files_ext: Option<bool>,
+  /// True if disk space should be pre-allocated for all files
+  pub preallocate_all: Option<bool>,
+
+  /// Port for incoming connections
+  pub listen_port: Option<u16>,
+  /// True if UPnP/NAT-PMP is enabled
+  pub upnp: Option<bool>,
+  /// Maximum global number of simultaneous connections
+  pub max_connec: Option<i64>,
+  /// Maximum number of simultaneous connections per torrent
+  pub max_connec_per_torrent: Option<i64>,
+  /// Maximum number of upload slots
+  pub max_uploads: Option<i64>,
+  /// Maximum number of upload slots per torrent
+  pub max_uploads_per_torrent: Option<i64>,
+
+  /// Global download speed limit (bytes/second), 0 means unlimited
+  pub dl_limit: Option<i64>,
+  /// Global upload speed limit (bytes/second), 0 means unlimited
+  pub up_limit: Option<i64>,
+  /// Alternative global download speed limit (bytes/second)
+  pub alt_dl_limit: Option<i64>,
+  /// Alternative global upload speed limit (bytes/second)
+  pub alt_up_limit: Option<i64>,
+
+  /// True if torrent queuing is enabled
+  pub queueing_enabled: Option<bool>,
+  /// Maximum number of active simultaneous downloads
+  pub max_active_downloads: Option<i64>,
+  /// Maximum number of active simultaneous downloads and uploads
+  pub max_active_torrents: Option<i64>,
+  /// Maximum number of active simultaneous uploads
+  pub max_active_uploads: Option<i64>,
+  /// If true torrents w/o any activity (stalled ones) will not be counted towards `max_active_*` limits
+  pub dont_count_slow_torrents: Option<bool>,
+
+  /// True if share ratio limit is enabled
+  pub max_ratio_enabled: Option<bool>,
+  /// Get the global share ratio limit
+  pub max_ratio: Option<f32>,
+  /// True if max seeding time limit is enabled
+  pub max_seeding_time_enabled: Option<bool>,
+  /// Number of minutes to seed a torrent
+  pub max_seeding_time: Option<i64>,
+  /// Action performed when a torrent reaches the maximum share ratio. 0: stop, 1: remove,
+  /// 2: remove with files, 3: enable super seeding
+  pub max_ratio_act: Option<i32>,
+
+  /// True if alternative limits should be applied according to schedule
+  pub scheduler_enabled: Option<bool>,
+  /// Scheduler starting hour
+  pub schedule_from_hour: Option<u8>,
+  /// Scheduler starting minute
+  pub schedule_from_min: Option<u8>,
+  /// Scheduler ending hour
+  pub schedule_to_hour: Option<u8>,
+  /// Scheduler ending minute
+  pub schedule_to_min: Option<u8>,
+  /// Scheduler days. 0: every day, 1: every weekday, 2: every weekend, 3-9: Monday to Sunday
+  pub scheduler_days: Option<u8>,
+
+  /// Currently selected language (e.g. en_GB for English)
+  pub locale: Option<String>,
+  /// Semicolon-separated list of domains to accept when performing Host header validation
+  pub web_ui_domain_list: Option<String>,
+  /// IP address to use for the WebUI
+  pub web_ui_address: Option<String>,
+  /// WebUI port
+  pub web_ui_port: Option<u16>,
+  /// True if UPnP is used for the WebUI port
+  pub web_ui_upnp: Option<bool>,
+  /// WebUI username
+  pub web_ui_username: Option<String>,
+  /// For API ≥ v2.3.0: Plaintext WebUI password, not readable, write-only
+  pub web_ui_password: Option<String>,
+  /// True if authentication challenge for loopback address (127.0.0.1) should be disabled
+  pub bypass_local_auth: Option<bool>,
+  /// True if webui authentication should be bypassed for clients whose ip resides within (at least) one of the subnets on the whitelist
+  pub bypass_auth_subnet_whitelist_enabled: Option<bool>,
+  /// (White)list of ipv4/ipv6 subnets for which webui authentication should be bypassed
+  pub bypass_auth_subnet_whitelist: Option<String>,
+  /// WebUI session timeout (seconds)
+  pub web_ui_session_timeout: Option<i64>,
+  /// Maximum failed login attempts before the client is banned
+  pub web_ui_max_auth_fail_count: Option<i64>,
+  /// Ban duration after too many failed login attempts (seconds)
+  pub web_ui_ban_duration: Option<i64>,
+  /// True if WebUI HTTPS access is enabled
+  pub use_https: Option<bool>,
+}
+
+/// 获取应用程序设置
+#[tauri::command]
+#[specta::specta]
+pub async fn get_preferences(state: State<'_, QBittorrentState>) -> Result<Preferences> {
+  let state = state.lock().await?;
+  state.get("app", "preferences", None::<&()>).await
+}
+
+/// 修改应用程序设置，只修改有值的字段
+#[tauri::command]
+#[specta::specta]
+pub async fn set_preferences(
+  state: State<'_, QBittorrentState>,
+  preferences: Preferences,
+) -> Result<()> {
+  let json = serde_json::to_string(&preferences)?;
+  let state = state.lock().await?;
+  state
+    .post("app", "setPreferences", &[("json", json.as_str())])
+    .await?;
+  Ok(())
+}
@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, State};
+use tokio::sync::OwnedMutexGuard;
+
+use super::{
+  Credentials, DEFAULT_PROFILE, QBittorrentError, QBittorrentState, QBittorrentStateInner,
+  TorrentInfo, convert::options_from, error::Result, events::emit_reset,
+};
+use crate::settings::{get_setting, set_setting};
+
+const PROFILES_KEY: &str = "profiles";
+const ACTIVE_PROFILE_KEY: &str = "activeProfile";
+
+/// 服务器配置
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+  /// 配置 ID
+  pub id: String,
+  /// 显示名称
+  pub name: String,
+  /// WebUI 地址
+  pub url: String,
+  /// 代理，为空字符串时不使用代理
+  pub proxy: Option<String>,
+  pub username: String,
+  pub password: String,
+}
+
+/// 两个服务器的种子对比结果
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TorrentComparison {
+  /// 只在第一个服务器中的种子
+  pub only_in_first: Vec<TorrentInfo>,
+  /// 只在第二个服务器中的种子
+  pub only_in_second: Vec<TorrentInfo>,
+  /// 两个服务器中都有的种子哈希
+  pub in_both: Vec<String>,
+}
+
+fn load_profiles() -> Result<Vec<Profile>> {
+  Ok(get_setting(PROFILES_KEY)?.unwrap_or_default())
+}
+
+fn find_profile(profile_id: &str) -> Result<Profile> {
+  match load_profiles()?.into_iter().find(|p| p.id == profile_id) {
+    Some(profile) => Ok(profile),
+    None => Err(QBittorrentError::ProfileNotFound(profile_id.to_string())),
+  }
+}
+
+/// 读取保存的当前服务器配置 ID
+pub(super) fn saved_active_profile() -> Option<String> {
+  match get_setting::<Option<String>>(ACTIVE_PROFILE_KEY) {
+    Ok(active) => active.flatten(),
+    Err(e) => {
+      warn!("Failed to get active profile: {}", e);
+      None
+    }
+  }
+}
+
+/// 连接指定的服务器配置，已连接或未使用服务器配置时直接返回
+pub(super) async fn connect(
+  state: &QBittorrentState,
+  profile_id: &str,
+) -> Result<OwnedMutexGuard<QBittorrentStateInner>> {
+  let mut server = state.lock_profile(profile_id).await;
+
+  if server.client.is_none() && profile_id != DEFAULT_PROFILE {
+    let profile = find_profile(profile_id)?;
+
+    // 连接或登录失败时清除连接，下次使用时重新连接
+    if let Err(e) = login_profile(&mut server, profile).await {
+      server.client = None;
+      return Err(e);
+    }
+  }
+
+  Ok(server)
+}
+
+/// 按服务器配置设置地址并登录
+async fn login_profile(server: &mut QBittorrentStateInner, profile: Profile) -> Result<()> {
+  server.configure(&profile.url, profile.proxy)?;
+
+  let credentials = Credentials {
+    username: profile.username,
+    password: profile.password,
+  };
+
+  if server.login(credentials, true).await? {
+    Ok(())
+  } else {
+    Err(QBittorrentError::Unauthorized)
+  }
+}
+
+/// 设置当前服务器配置，清空旧服务器同步的数据并为其中的种子发送删除事件
+pub(super) async fn activate(
+  app: &AppHandle,
+  state: &QBittorrentState,
+  profile_id: &str,
+) -> Result<()> {
+  let old_profile = state.active_profile().await;
+  let active = (profile_id != DEFAULT_PROFILE).then(|| profile_id.to_string());
+  *state.active.lock().await = active.clone();
+  set_setting(ACTIVE_PROFILE_KEY, active)?;
+
+  if old_profile != profile_id {
+    emit_reset(app, &mut *state.lock_profile(&old_profile).await);
+  }
+
+  Ok(())
+}
+
+/// 断开服务器配置的连接，并为已同步的种子发送删除事件
+async fn disconnect(app: &AppHandle, state: &QBittorrentState, profile_id: &str) {
+  let server = state.servers.lock().await.remove(profile_id);
+
+  if let Some(server) = server {
+    emit_reset(app, &mut *server.lock().await);
+  }
+}
+
+fn torrent_hash(info: &TorrentInfo) -> Option<&String> {
+  info.hash.as_ref().or(info.infohash_v1.as_ref())
+}
+
+/// 获取服务器配置列表
+#[tauri::command]
+#[specta::specta]
+pub fn get_profiles() -> Result<Vec<Profile>> {
+  load_profiles()
+}
+
+/// 保存服务器配置
+#[tauri::command]
+#[specta::specta]
+pub async fn save_profile(
+  app: AppHandle,
+  state: State<'_, QBittorrentState>,
+  profile: Profile,
+) -> Result<()> {
+  let mut profiles = load_profiles()?;
+
+  // 断开旧的连接，下次使用时按新配置重新连接（包括当前使用的配置）
+  disconnect(&app, &state, &profile.id).await;
+
+  if let Some(existed) = profiles.iter_mut().find(|p| p.id == profile.id) {
+    *existed = profile;
+  } else {
+    profiles.push(profile);
+  }
+
+  Ok(set_setting(PROFILES_KEY, profiles)?)
+}
+
+/// 删除服务器配置
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_profile(
+  app: AppHandle,
+  state: State<'_, QBittorrentState>,
+  profile_id: String,
+) -> Result<()> {
+  let mut profiles = load_profiles()?;
+  profiles.retain(|p| p.id != profile_id);
+  set_setting(PROFILES_KEY, profiles)?;
+
+  disconnect(&app, &state, &profile_id).await;
+
+  let mut active = state.active.lock().await;
+
+  if active.as_ref() == Some(&profile_id) {
+    *active = None;
+    set_setting(ACTIVE_PROFILE_KEY, None::<String>)?;
+  }
+
+  Ok(())
+}
+
+/// 获取当前服务器配置 ID
+#[tauri::command]
+#[specta::specta]
+pub async fn get_active_profile(state: State<'_, QBittorrentState>) -> Result<Option<String>> {
+  let active = state.active_profile().await;
+  Ok((active != DEFAULT_PROFILE).then_some(active))
+}
+
+/// 切换服务器配置
+///
+/// 清空旧服务器同步的数据并为其中的种子发送删除事件，之后的同步使用新的服务器。
+#[tauri::command]
+#[specta::specta]
+pub async fn switch_profile(
+  app: AppHandle,
+  state: State<'_, QBittorrentState>,
+  profile_id: String,
+) -> Result<()> {
+  connect(&state, &profile_id).await?;
+  activate(&app, &state, &profile_id).await
+}
+
+/// 将种子转移到另一个服务器
+///
+/// 以磁力链接添加到目标服务器并保留分类、标签与保存路径，`delete_source` 为 `true` 时从源服务器删除已添加的种子（保留文件）。
+#[tauri::command]
+#[specta::specta]
+pub async fn transfer_torrents(
+  state: State<'_, QBittorrentState>,
+  from: String,
+  to: String,
+  hashes: Vec<String>,
+  delete_source: bool,
+) -> Result<()> {
+  if from == to {
+    return Err(QBittorrentError::Other(
+      "Source and target profiles are the same".to_string(),
+    ));
+  }
+
+  // 哈希为空时 qBittorrent 会返回所有种子
+  if hashes.is_empty() {
+    return Err(QBittorrentError::NotFound);
+  }
+
+  let torrents = connect(&state, &from)
+    .await?
+    .torrents_info(Some(&hashes))
+    .await?;
+  let mut sent = vec![];
+
+  {
+    let target = connect(&state, &to).await?;
+
+    for info in &torrents {
+      let (Some(hash), Some(magnet_uri)) = (torrent_hash(info), &info.magnet_uri) else {
+        continue;
+      };
+
+      target
+        .add_torrent_urls(magnet_uri, &options_from(info))
+        .await?;
+      sent.push(hash.clone());
+    }
+  }
+
+  if sent.is_empty() {
+    return Err(QBittorrentError::NotFound);
+  }
+
+  if delete_source {
+    let source = connect(&state, &from).await?;
+    source
+      .post(
+        "torrents",
+        "delete",
+        &[
+          ("hashes", sent.join("|").as_str()),
+          ("deleteFiles", "false"),
+        ],
+      )
+      .await?;
+  }
+
+  Ok(())
+}
+
+/// 对比两个服务器中的种子
+#[tauri::command]
+#[specta::specta]
+pub async fn compare_torrents(
+  state: State<'_, QBittorrentState>,
+  first: String,
+  second: String,
+) -> Result<TorrentComparison> {
+  let first = connect(&state, &first).await?.torrents_info(None).await?;
+  let second = connect(&state, &second).await?.torrents_info(None).await?;
+
+  let first_hashes = first
+    .iter()
+    .filter_map(torrent_hash)
+    .collect::<HashSet<_>>();
+  let second_hashes = second
+    .iter()
+    .filter_map(torrent_hash)
+    .collect::<HashSet<_>>();
+
+  let in_both = first_hashes
+    .intersection(&second_hashes)
+    .map(|hash| hash.to_string())
+    .collect();
+  let only_in_first = first
+    .iter()
+    .filter(|info| torrent_hash(info).is_none_or(|hash| !second_hashes.contains(hash)))
+    .cloned()
+    .collect();
+  let only_in_second = second
+    .iter()
+    .filter(|info| torrent_hash(info).is_none_or(|hash| !first_hashes.contains(hash)))
+    .cloned()
+    .collect();
+
+  Ok(TorrentComparison {
+    only_in_first,
+    only_in_second,
+    in_both,
+  })
+}
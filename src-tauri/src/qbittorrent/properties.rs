@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result, sync::merge_fields};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TorrentProperties {
+  /// Torrent hash
+  pub hash: Option<String>,
+  pub infohash_v1: Option<String>,
+  pub infohash_v2: Option<String>,
+  /// Torrent name
+  pub name: Option<String>,
+  /// Torrent save path
+  pub save_path: Option<String>,
+  /// Torrent download path
+  pub download_path: Option<String>,
+  /// Torrent creation date (Unix timestamp)
+  pub creation_date: Option<i64>,
+  /// Torrent piece size (bytes)
+  pub piece_size: Option<i64>,
+  /// Torrent comment
+  pub comment: Option<String>,
+  /// Total data wasted for torrent (bytes)
+  pub total_wasted: Option<i64>,
+  /// Total data uploaded for torrent (bytes)
+  pub total_uploaded: Option<i64>,
+  /// Total data uploaded this session (bytes)
+  pub total_uploaded_session: Option<i64>,
+  /// Total data downloaded for torrent (bytes)
+  pub total_downloaded: Option<i64>,
+  /// Total data downloaded this session (bytes)
+  pub total_downloaded_session: Option<i64>,
+  /// Torrent upload limit (bytes/s)
+  pub up_limit: Option<i64>,
+  /// Torrent download limit (bytes/s)
+  pub dl_limit: Option<i64>,
+  /// Torrent elapsed time (seconds)
+  pub time_elapsed: Option<i64>,
+  /// Torrent elapsed time while complete (seconds)
+  pub seeding_time: Option<i64>,
+  /// Torrent connection count
+  pub nb_connections: Option<i64>,
+  /// Torrent connection count limit
+  pub nb_connections_limit: Option<i64>,
+  /// Torrent share ratio
+  pub share_ratio: Option<f32>,
+  /// When this torrent was added (unix timestamp)
+  pub addition_date: Option<i64>,
+  /// Torrent completion date (unix timestamp)
+  pub completion_date: Option<i64>,
+  /// Torrent creator
+  pub created_by: Option<String>,
+  /// Torrent average download speed (bytes/second)
+  pub dl_speed_avg: Option<i64>,
+  /// Torrent download speed (bytes/second)
+  pub dl_speed: Option<i64>,
+  /// Torrent ETA (seconds)
+  pub eta: Option<i64>,
+  /// Last seen complete date (unix timestamp)
+  pub last_seen: Option<i64>,
+  /// Number of peers connected to
+  pub peers: Option<i64>,
+  /// Number of peers in the swarm
+  pub peers_total: Option<i64>,
+  /// Number of pieces owned
+  pub pieces_have: Option<i64>,
+  /// Number of pieces of the torrent
+  pub pieces_num: Option<i64>,
+  /// Number of seconds until the next announce
+  pub reannounce: Option<i64>,
+  /// Number of seeds connected to
+  pub seeds: Option<i64>,
+  /// Number of seeds in the swarm
+  pub seeds_total: Option<i64>,
+  /// Torrent total size (bytes)
+  pub total_size: Option<i64>,
+  /// Torrent average upload speed (bytes/second)
+  pub up_speed_avg: Option<i64>,
+  /// Torrent upload speed (bytes/second)
+  pub up_speed: Option<i64>,
+  /// True if torrent is from a private tracker
+  #[serde(alias = "isPrivate")]
+  pub is_private: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct Tracker {
+  /// Tracker url
+  pub url: String,
+  /// Tracker status. 0: disabled (used for DHT, PeX, and LSD), 1: not contacted yet,
+  /// 2: working, 3: updating, 4: not working
+  pub status: i32,
+  /// Tracker priority tier. Lower tier trackers are tried before higher tiers.
+  /// Tier numbers are valid when `>= 0`, `< 0` is used as placeholder when `tier` does not exist for special entries (such as DHT).
+  pub tier: i32,
+  /// Number of peers for current torrent, as reported by the tracker
+  pub num_peers: Option<i64>,
+  /// Number of seeds for current torrent, as reported by the tracker
+  pub num_seeds: Option<i64>,
+  /// Number of leeches for current torrent, as reported by the tracker
+  pub num_leeches: Option<i64>,
+  /// Number of completed downloads for current torrent, as reported by the tracker
+  pub num_downloaded: Option<i64>,
+  /// Tracker message (there is no way of knowing what this message is - it's up to tracker admins)
+  pub msg: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WebSeed {
+  /// URL of the web seed
+  pub url: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PeerInfo {
+  /// Client name of the peer
+  pub client: Option<String>,
+  /// Client name parsed from peer ID
+  pub peer_id_client: Option<String>,
+  /// Connection type, e.g. BT, uTP
+  pub connection: Option<String>,
+  pub country: Option<String>,
+  pub country_code: Option<String>,
+  /// Download speed (bytes/s)
+  pub dl_speed: Option<i64>,
+  /// Amount of data downloaded from the peer (bytes)
+  pub downloaded: Option<i64>,
+  /// Files the peer is transferring
+  pub files: Option<String>,
+  pub flags: Option<String>,
+  pub flags_desc: Option<String>,
+  pub ip: Option<String>,
+  pub port: Option<u16>,
+  /// Peer progress (percentage/100)
+  pub progress: Option<f32>,
+  /// Relevance of the peer (percentage/100)
+  pub relevance: Option<f32>,
+  /// Upload speed (bytes/s)
+  pub up_speed: Option<i64>,
+  /// Amount of data uploaded to the peer (bytes)
+  pub uploaded: Option<i64>,
+}
+
+impl PeerInfo {
+  fn merge(&mut self, delta: PeerInfo) {
+    merge_fields!(
+      self,
+      delta,
+      [
+        client,
+        peer_id_client,
+        connection,
+        country,
+        country_code,
+        dl_speed,
+        downloaded,
+        files,
+        flags,
+        flags_desc,
+        ip,
+        port,
+        progress,
+        relevance,
+        up_speed,
+        uploaded,
+      ]
+    );
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TorrentPeers {
+  #[serde(default)]
+  full_update: bool,
+  rid: u32,
+  peers: Option<HashMap<String, PeerInfo>>,
+  peers_removed: Option<Vec<String>>,
+}
+
+/// 单个种子合并后的用户列表
+#[derive(Debug, Default)]
+pub struct PeersData {
+  rid: u32,
+  peers: HashMap<String, PeerInfo>,
+}
+
+impl PeersData {
+  fn apply(&mut self, delta: TorrentPeers) {
+    if delta.full_update {
+      self.peers.clear();
+    }
+
+    self.rid = delta.rid;
+
+    for (key, peer) in delta.peers.unwrap_or_default() {
+      if let Some(existed) = self.peers.get_mut(&key) {
+        existed.merge(peer);
+      } else {
+        self.peers.insert(key, peer);
+      }
+    }
+
+    for key in delta.peers_removed.unwrap_or_default() {
+      self.peers.remove(&key);
+    }
+  }
+}
+
+/// 获取种子属性
+#[tauri::command]
+#[specta::specta]
+pub async fn get_torrent_properties(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<TorrentProperties> {
+  let state = state.lock().await?;
+  state
+    .get("torrents", "properties", Some(&[("hash", hash.as_str())]))
+    .await
+}
+
+/// 获取种子的 Tracker
+#[tauri::command]
+#[specta::specta]
+pub async fn get_torrent_trackers(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<Vec<Tracker>> {
+  let state = state.lock().await?;
+  state
+    .get("torrents", "trackers", Some(&[("hash", hash.as_str())]))
+    .await
+}
+
+/// 获取种子的 Web 种子
+#[tauri::command]
+#[specta::specta]
+pub async fn get_torrent_webseeds(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<Vec<WebSeed>> {
+  let state = state.lock().await?;
+  state
+    .get("torrents", "webseeds", Some(&[("hash", hash.as_str())]))
+    .await
+}
+
+/// 获取种子的用户列表
+///
+/// 每个种子单独记录 `rid`，只请求增量并在后端合并，返回完整的用户列表。
+#[tauri::command]
+#[specta::specta]
+pub async fn get_torrent_peers(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<HashMap<String, PeerInfo>> {
+  let mut state = state.lock().await?;
+  let rid = state.peers.get(&hash).map(|p| p.rid).unwrap_or_default();
+  let delta: TorrentPeers = state
+    .get(
+      "sync",
+      "torrentPeers",
+      Some(&[("hash", hash.as_str()), ("rid", rid.to_string().as_str())]),
+    )
+    .await?;
+
+  let peers = state.peers.entry(hash).or_default();
+  peers.apply(delta);
+  Ok(peers.peers.clone())
+}
+
+/// 获取分块状态
+///
+/// 0：未下载；1：正在下载；2：已下载
+#[tauri::command]
+#[specta::specta]
+pub async fn get_piece_states(state: State<'_, QBittorrentState>, hash: String) -> Result<Vec<u8>> {
+  let state = state.lock().await?;
+  state
+    .get("torrents", "pieceStates", Some(&[("hash", hash.as_str())]))
+    .await
+}
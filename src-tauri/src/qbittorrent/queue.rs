@@ -0,0 +1,142 @@
+use std::cmp::Reverse;
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, TorrentInfo, error::Result};
+use crate::{db::DbState, scrape::get_movie_code};
+
+/// 队列排序规则
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Type)]
+pub enum QueueOrder {
+  /// 发布日期较新的优先
+  #[default]
+  NewestRelease,
+  /// 发布日期较早的优先
+  OldestRelease,
+  /// 体积较小的优先
+  SmallestFirst,
+  /// 先添加的优先
+  FirstAdded,
+}
+
+async fn set_priority(
+  state: &State<'_, QBittorrentState>,
+  method_name: &str,
+  hashes: &[String],
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("torrents", method_name, &[("hashes", hashes.join("|"))])
+    .await?;
+  Ok(())
+}
+
+/// 提高队列优先级
+#[tauri::command]
+#[specta::specta]
+pub async fn increase_priority(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+) -> Result<()> {
+  set_priority(&state, "increasePrio", &hashes).await
+}
+
+/// 降低队列优先级
+#[tauri::command]
+#[specta::specta]
+pub async fn decrease_priority(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+) -> Result<()> {
+  set_priority(&state, "decreasePrio", &hashes).await
+}
+
+/// 移至队列顶部
+#[tauri::command]
+#[specta::specta]
+pub async fn top_priority(state: State<'_, QBittorrentState>, hashes: Vec<String>) -> Result<()> {
+  set_priority(&state, "topPrio", &hashes).await
+}
+
+/// 移至队列底部
+#[tauri::command]
+#[specta::specta]
+pub async fn bottom_priority(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+) -> Result<()> {
+  set_priority(&state, "bottomPrio", &hashes).await
+}
+
+/// 按规则重新排列下载队列，返回排序后的种子哈希
+///
+/// 发布日期取自已刮削的视频信息，没有信息的种子排在最后。未指定 `order` 时新发布的优先。
+#[tauri::command]
+#[specta::specta]
+pub async fn reorder_queue(
+  state: State<'_, QBittorrentState>,
+  db_state: State<'_, DbState>,
+  order: Option<QueueOrder>,
+) -> Result<Vec<String>> {
+  let order = order.unwrap_or_default();
+  let state = state.lock().await?;
+
+  // 优先级小于等于 0 表示未排队
+  let queued = state
+    .torrents_info(None)
+    .await?
+    .into_iter()
+    .filter(|info| info.hash.is_some() && info.priority.is_some_and(|p| p > 0))
+    .collect::<Vec<_>>();
+
+  let mut keyed = vec![];
+
+  {
+    let mut db = db_state.lock().await;
+
+    for info in queued {
+      let release_date = match info.name.as_ref().and_then(get_movie_code) {
+        Some(code) => db.find_release_date(&code).await?,
+        None => None,
+      };
+      keyed.push((info, release_date));
+    }
+  }
+
+  sort_queue(&mut keyed, order);
+
+  let hashes = keyed
+    .into_iter()
+    .filter_map(|(info, _)| info.hash)
+    .collect::<Vec<_>>();
+
+  // 依次移至底部，最终顺序即为排序后的顺序
+  for hash in &hashes {
+    debug!("Moving {} to the bottom of the queue", hash);
+    state
+      .post("torrents", "bottomPrio", &[("hashes", hash.as_str())])
+      .await?;
+  }
+
+  Ok(hashes)
+}
+
+fn sort_queue(keyed: &mut [(TorrentInfo, Option<i64>)], order: QueueOrder) {
+  match order {
+    QueueOrder::NewestRelease => {
+      keyed.sort_by_key(|(_, date)| (date.is_none(), Reverse(*date)));
+    }
+    QueueOrder::OldestRelease => {
+      keyed.sort_by_key(|(_, date)| (date.is_none(), *date));
+    }
+    QueueOrder::SmallestFirst => {
+      keyed.sort_by_key(|(info, _)| info.size.unwrap_or(i64::MAX));
+    }
+    QueueOrder::FirstAdded => {
+      keyed.sort_by_key(|(info, _)| info.added_on.unwrap_or(i64::MAX));
+    }
+  }
+}
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentError, QBittorrentState, error::Result};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RssArticle {
+  pub id: String,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub date: Option<String>,
+  pub link: Option<String>,
+  #[serde(rename = "torrentURL")]
+  pub torrent_url: Option<String>,
+  pub category: Option<String>,
+  pub author: Option<String>,
+  pub is_read: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RssFeed {
+  pub uid: String,
+  pub url: String,
+  pub title: Option<String>,
+  pub last_build_date: Option<String>,
+  pub is_loading: Option<bool>,
+  pub has_error: Option<bool>,
+  /// 仅在 `with_data` 为 `true` 时返回
+  pub articles: Option<Vec<RssArticle>>,
+}
+
+/// RSS 订阅或文件夹
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(untagged)]
+pub enum RssItem {
+  Feed(RssFeed),
+  Folder(HashMap<String, RssItem>),
+}
+
+/// RSS 自动下载规则
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RssRule {
+  /// Whether the rule is enabled
+  pub enabled: Option<bool>,
+  /// The substring that the torrent name must contain
+  pub must_contain: Option<String>,
+  /// The substring that the torrent name must not contain
+  pub must_not_contain: Option<String>,
+  /// Enable regex mode in `must_contain` and `must_not_contain`
+  pub use_regex: Option<bool>,
+  /// Episode filter definition
+  pub episode_filter: Option<String>,
+  /// Enable smart episode filter
+  pub smart_filter: Option<bool>,
+  /// The list of episode IDs already matched by smart filter
+  pub previously_matched_episodes: Option<Vec<String>>,
+  /// The feed URLs the rule applies to
+  pub affected_feeds: Option<Vec<String>>,
+  /// Ignore subsequent rule matches (days)
+  pub ignore_days: Option<i64>,
+  /// The rule last match time
+  pub last_match: Option<String>,
+  /// Add matched torrent in paused mode
+  pub add_paused: Option<bool>,
+  /// Assign category to the torrent
+  pub assigned_category: Option<String>,
+  /// Save torrent to the given directory
+  pub save_path: Option<String>,
+}
+
+impl RssRule {
+  /// 创建匹配番号前缀的规则，如 `SSIS` 匹配 `SSIS-001`、`ssis001`
+  ///
+  /// 没有非空的前缀时返回错误，避免创建匹配所有番号的规则。
+  pub fn for_movie_code_prefixes(prefixes: &[String], affected_feeds: Vec<String>) -> Result<Self> {
+    let prefixes = prefixes
+      .iter()
+      .map(|prefix| regex::escape(prefix.trim()))
+      .filter(|prefix| !prefix.is_empty())
+      .collect::<Vec<_>>();
+
+    if prefixes.is_empty() {
+      return Err(QBittorrentError::Other(
+        "No movie code prefixes".to_string(),
+      ));
+    }
+
+    Ok(Self {
+      enabled: Some(true),
+      must_contain: Some(format!(r"(?i)\b({})[-_]?\d+", prefixes.join("|"))),
+      use_regex: Some(true),
+      affected_feeds: Some(affected_feeds),
+      ..Default::default()
+    })
+  }
+}
+
+/// 添加 RSS 文件夹，`path` 以 `\` 分隔
+#[tauri::command]
+#[specta::specta]
+pub async fn add_rss_folder(state: State<'_, QBittorrentState>, path: String) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("rss", "addFolder", &[("path", path.as_str())])
+    .await?;
+  Ok(())
+}
+
+/// 添加 RSS 订阅
+#[tauri::command]
+#[specta::specta]
+pub async fn add_rss_feed(
+  state: State<'_, QBittorrentState>,
+  url: String,
+  path: Option<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "rss",
+      "addFeed",
+      &[
+        ("url", url.as_str()),
+        ("path", path.as_deref().unwrap_or_default()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除 RSS 订阅或文件夹
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_rss_item(state: State<'_, QBittorrentState>, path: String) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("rss", "removeItem", &[("path", path.as_str())])
+    .await?;
+  Ok(())
+}
+
+/// 移动 RSS 订阅或文件夹
+#[tauri::command]
+#[specta::specta]
+pub async fn move_rss_item(
+  state: State<'_, QBittorrentState>,
+  item_path: String,
+  dest_path: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "rss",
+      "moveItem",
+      &[
+        ("itemPath", item_path.as_str()),
+        ("destPath", dest_path.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 获取 RSS 订阅，`with_data` 为 `true` 时包含文章
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rss_items(
+  state: State<'_, QBittorrentState>,
+  with_data: bool,
+) -> Result<HashMap<String, RssItem>> {
+  let state = state.lock().await?;
+  state
+    .get(
+      "rss",
+      "items",
+      Some(&[("withData", with_data.to_string().as_str())]),
+    )
+    .await
+}
+
+/// 刷新 RSS 订阅或文件夹
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_rss_item(state: State<'_, QBittorrentState>, item_path: String) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("rss", "refreshItem", &[("itemPath", item_path.as_str())])
+    .await?;
+  Ok(())
+}
+
+/// 标记为已读，未指定 `article_id` 时标记订阅中的所有文章
+#[tauri::command]
+#[specta::specta]
+pub async fn mark_rss_as_read(
+  state: State<'_, QBittorrentState>,
+  item_path: String,
+  article_id: Option<String>,
+) -> Result<()> {
+  let mut form = vec![("itemPath", item_path)];
+
+  if let Some(article_id) = article_id {
+    form.push(("articleId", article_id));
+  }
+
+  let state = state.lock().await?;
+  state.post("rss", "markAsRead", &form).await?;
+  Ok(())
+}
+
+/// 获取自动下载规则
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rss_rules(state: State<'_, QBittorrentState>) -> Result<HashMap<String, RssRule>> {
+  let state = state.lock().await?;
+  state.get("rss", "rules", None::<&()>).await
+}
+
+/// 添加或修改自动下载规则
+#[tauri::command]
+#[specta::specta]
+pub async fn set_rss_rule(
+  state: State<'_, QBittorrentState>,
+  rule_name: String,
+  rule: RssRule,
+) -> Result<()> {
+  let rule_def = serde_json::to_string(&rule)?;
+  let state = state.lock().await?;
+  state
+    .post(
+      "rss",
+      "setRule",
+      &[
+        ("ruleName", rule_name.as_str()),
+        ("ruleDef", rule_def.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 添加匹配番号前缀的自动下载规则
+#[tauri::command]
+#[specta::specta]
+pub async fn set_movie_code_rss_rule(
+  state: State<'_, QBittorrentState>,
+  rule_name: String,
+  prefixes: Vec<String>,
+  affected_feeds: Vec<String>,
+  category: Option<String>,
+) -> Result<()> {
+  let rule = RssRule {
+    assigned_category: category,
+    ..RssRule::for_movie_code_prefixes(&prefixes, affected_feeds)?
+  };
+  set_rss_rule(state, rule_name, rule).await
+}
+
+/// 重命名自动下载规则
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_rss_rule(
+  state: State<'_, QBittorrentState>,
+  rule_name: String,
+  new_rule_name: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "rss",
+      "renameRule",
+      &[
+        ("ruleName", rule_name.as_str()),
+        ("newRuleName", new_rule_name.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除自动下载规则
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_rss_rule(state: State<'_, QBittorrentState>, rule_name: String) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("rss", "removeRule", &[("ruleName", rule_name.as_str())])
+    .await?;
+  Ok(())
+}
+
+/// 获取匹配规则的文章，返回订阅名称与文章标题
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rss_matching_articles(
+  state: State<'_, QBittorrentState>,
+  rule_name: String,
+) -> Result<HashMap<String, Vec<String>>> {
+  let state = state.lock().await?;
+  state
+    .get(
+      "rss",
+      "matchingArticles",
+      Some(&[("ruleName", rule_name.as_str())]),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use regex::Regex;
+
+  use super::*;
+
+  #[test]
+  fn test_movie_code_rule() {
+    let rule =
+      RssRule::for_movie_code_prefixes(&["SSIS".to_string(), "IPX".to_string()], vec![]).unwrap();
+    let must_contain = rule.must_contain.unwrap();
+    assert_eq!(must_contain, r"(?i)\b(SSIS|IPX)[-_]?\d+");
+
+    let re = Regex::new(&must_contain).unwrap();
+    assert!(re.is_match("[HD] SSIS-001 title"));
+    assert!(re.is_match("ipx123.mp4"));
+    assert!(!re.is_match("ABP-123"));
+    assert!(!re.is_match("XSSIS-001"));
+
+    assert!(RssRule::for_movie_code_prefixes(&[], vec![]).is_err());
+    assert!(RssRule::for_movie_code_prefixes(&[" ".to_string(), String::new()], vec![]).is_err());
+  }
+}
@@ -0,0 +1,331 @@
+use std::{cmp::Reverse, time::Duration};
+
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use specta::Type;
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+use crate::scrape::get_movie_code;
+
+/// 查找番号时等待搜索完成的最长时间
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(30);
+const SEARCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Deserialize)]
+struct SearchJob {
+  id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum SearchJobStatus {
+  Running,
+  Stopped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchStatus {
+  /// ID of the search job
+  pub id: i64,
+  /// Current status of the search job
+  pub status: SearchJobStatus,
+  /// Total number of results
+  pub total: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+  /// URL of the torrent's description page
+  pub descr_link: Option<String>,
+  /// Name of the file
+  pub file_name: String,
+  /// Size of the file in Bytes
+  pub file_size: i64,
+  /// Torrent download link (usually either .torrent file or magnet link)
+  pub file_url: String,
+  /// Number of leechers
+  pub nb_leechers: i64,
+  /// Number of seeders
+  pub nb_seeders: i64,
+  /// URL of the torrent site
+  pub site_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchResults {
+  pub results: Vec<SearchResult>,
+  /// Current status of the search job
+  pub status: SearchJobStatus,
+  /// Total number of results
+  pub total: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SearchCategory {
+  pub id: String,
+  pub name: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPlugin {
+  /// Whether the plugin is enabled
+  pub enabled: bool,
+  /// Full name of the plugin
+  pub full_name: String,
+  /// Short name of the plugin
+  pub name: String,
+  /// List of category objects
+  pub supported_categories: Option<Vec<SearchCategory>>,
+  /// URL of the torrent site
+  pub url: Option<String>,
+  /// Installed version of the plugin
+  pub version: Option<String>,
+}
+
+/// 开始搜索，返回搜索任务 ID
+///
+/// 未指定 `plugins` 时使用所有已启用的插件，未指定 `category` 时搜索所有分类。
+#[tauri::command]
+#[specta::specta]
+pub async fn start_search(
+  state: State<'_, QBittorrentState>,
+  pattern: String,
+  plugins: Option<Vec<String>>,
+  category: Option<String>,
+) -> Result<i64> {
+  let plugins = plugins
+    .map(|plugins| plugins.join("|"))
+    .unwrap_or_else(|| "enabled".to_string());
+  let category = category.unwrap_or_else(|| "all".to_string());
+
+  let state = state.lock().await?;
+  let result = state
+    .post(
+      "search",
+      "start",
+      &[
+        ("pattern", pattern.as_str()),
+        ("plugins", plugins.as_str()),
+        ("category", category.as_str()),
+      ],
+    )
+    .await?;
+  let job: SearchJob = serde_json::from_str(&result)?;
+  Ok(job.id)
+}
+
+/// 停止搜索
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_search(state: State<'_, QBittorrentState>, id: i64) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("search", "stop", &[("id", id.to_string())])
+    .await?;
+  Ok(())
+}
+
+/// 获取搜索状态，未指定 `id` 时获取所有搜索任务
+#[tauri::command]
+#[specta::specta]
+pub async fn get_search_status(
+  state: State<'_, QBittorrentState>,
+  id: Option<i64>,
+) -> Result<Vec<SearchStatus>> {
+  let state = state.lock().await?;
+
+  match id {
+    Some(id) => {
+      state
+        .get("search", "status", Some(&[("id", id.to_string())]))
+        .await
+    }
+    None => state.get("search", "status", None::<&()>).await,
+  }
+}
+
+/// 获取搜索结果
+#[tauri::command]
+#[specta::specta]
+pub async fn get_search_results(
+  state: State<'_, QBittorrentState>,
+  id: i64,
+  limit: Option<i64>,
+  offset: Option<i64>,
+) -> Result<SearchResults> {
+  let mut query = vec![("id", id.to_string())];
+
+  if let Some(limit) = limit {
+    query.push(("limit", limit.to_string()));
+  }
+
+  if let Some(offset) = offset {
+    query.push(("offset", offset.to_string()));
+  }
+
+  let state = state.lock().await?;
+  state.get("search", "results", Some(&query)).await
+}
+
+/// 删除搜索任务
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_search(state: State<'_, QBittorrentState>, id: i64) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post("search", "delete", &[("id", id.to_string())])
+    .await?;
+  Ok(())
+}
+
+/// 获取搜索插件
+#[tauri::command]
+#[specta::specta]
+pub async fn get_search_plugins(state: State<'_, QBittorrentState>) -> Result<Vec<SearchPlugin>> {
+  let state = state.lock().await?;
+  state.get("search", "plugins", None::<&()>).await
+}
+
+/// 安装搜索插件，`sources` 为插件的链接或文件路径
+#[tauri::command]
+#[specta::specta]
+pub async fn install_search_plugin(
+  state: State<'_, QBittorrentState>,
+  sources: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "search",
+      "installPlugin",
+      &[("sources", sources.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 启用或禁用搜索插件
+#[tauri::command]
+#[specta::specta]
+pub async fn enable_search_plugin(
+  state: State<'_, QBittorrentState>,
+  names: Vec<String>,
+  enable: bool,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "search",
+      "enablePlugin",
+      &[("names", names.join("|")), ("enable", enable.to_string())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 去除番号不同的结果（名称中没有番号的结果保留），按做种数与大小排序
+fn rank_results(results: Vec<SearchResult>, code: &str) -> Vec<SearchResult> {
+  let mut results = results
+    .into_iter()
+    .filter(|result| get_movie_code(&result.file_name).is_none_or(|c| c == code))
+    .collect::<Vec<_>>();
+  results.sort_by_key(|result| (Reverse(result.nb_seeders), Reverse(result.file_size)));
+  results
+}
+
+/// 等待搜索完成或超时，返回搜索结果
+async fn wait_for_results(state: &State<'_, QBittorrentState>, id: i64) -> Result<SearchResults> {
+  let started = tokio::time::Instant::now();
+
+  loop {
+    tokio::time::sleep(SEARCH_POLL_INTERVAL).await;
+    let status = get_search_status(state.clone(), Some(id)).await?;
+
+    if status
+      .iter()
+      .all(|status| status.status == SearchJobStatus::Stopped)
+      || started.elapsed() >= SEARCH_TIMEOUT
+    {
+      break;
+    }
+  }
+
+  get_search_results(state.clone(), id, None, None).await
+}
+
+/// 搜索名称中的番号，去除番号不同的结果
+///
+/// 等待搜索完成或超时后停止并删除搜索任务（出错时也是如此），名称中没有番号时返回空列表。
+#[tauri::command]
+#[specta::specta]
+pub async fn search_movie_code(
+  state: State<'_, QBittorrentState>,
+  name: String,
+) -> Result<Vec<SearchResult>> {
+  let Some(code) = get_movie_code(&name) else {
+    return Ok(vec![]);
+  };
+
+  debug!("Searching for {}", code);
+  let id = start_search(state.clone(), code.clone(), None, None).await?;
+  let results = wait_for_results(&state, id).await;
+
+  if let Err(e) = stop_search(state.clone(), id).await {
+    warn!("Failed to stop search {}: {}", id, e);
+  }
+
+  if let Err(e) = delete_search(state, id).await {
+    warn!("Failed to delete search {}: {}", id, e);
+  }
+
+  Ok(rank_results(results?.results, &code))
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn result(file_name: &str, file_size: i64, nb_seeders: i64) -> SearchResult {
+    SearchResult {
+      descr_link: None,
+      file_name: file_name.to_string(),
+      file_size,
+      file_url: String::new(),
+      nb_leechers: 0,
+      nb_seeders,
+      site_url: None,
+    }
+  }
+
+  #[test]
+  fn test_rank_results() {
+    let results = vec![
+      result("SSIS-001 720p", 1000, 10),
+      result("SSIS-002", 5000, 100),
+      result("[HD] SSIS-001 1080p", 5000, 10),
+      result("ssis001.mp4", 3000, 50),
+      result("no code here", 2000, 20),
+    ];
+
+    let names = rank_results(results, "SSIS-001")
+      .into_iter()
+      .map(|result| result.file_name)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      names,
+      vec![
+        "ssis001.mp4",
+        "no code here",
+        "[HD] SSIS-001 1080p",
+        "SSIS-001 720p"
+      ]
+    );
+  }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::State;
+
+use super::{
+  QBittorrentState, TorrentContent,
+  error::Result,
+  files::{is_video, split_path},
+};
+use crate::{
+  scrape::get_movie_code,
+  settings::{get_setting, set_setting},
+};
+
+const SELECT_RULES_KEY: &str = "autoSelectRules";
+const SMALL_FILE_THRESHOLD_KEY: &str = "smallFileThreshold";
+const DEFAULT_SMALL_FILE_THRESHOLD: i64 = 200 * 1024 * 1024;
+
+/// 不下载
+const PRIORITY_DO_NOT_DOWNLOAD: i32 = 0;
+/// 正常
+const PRIORITY_NORMAL: i32 = 1;
+
+const SUBTITLE_EXTENSIONS: &[&str] = &["srt", "ass", "ssa", "sub", "idx", "vtt", "sup"];
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "gif", "bmp", "webp"];
+const ARCHIVE_EXTENSIONS: &[&str] = &["zip", "rar", "7z", "tar", "gz"];
+const ADVERTISEMENT_EXTENSIONS: &[&str] = &[
+  "url", "htm", "html", "mht", "txt", "lnk", "exe", "apk", "chm",
+];
+
+/// 文件分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum FileKind {
+  /// 正片
+  MainVideo,
+  /// 分段的正片
+  MultiPart,
+  Subtitle,
+  Sample,
+  Advertisement,
+  Image,
+  Archive,
+  Other,
+}
+
+/// 自动选择文件的规则
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectRules {
+  /// 小于此大小（字节）的视频视为广告，其他文件小于此大小时不下载
+  pub small_file_threshold: i64,
+  /// 文件名包含这些关键字时视为预览
+  pub sample_keywords: Vec<String>,
+  /// 文件名包含这些关键字时视为广告
+  pub advertisement_keywords: Vec<String>,
+  pub keep_subtitles: bool,
+  pub keep_images: bool,
+  pub keep_archives: bool,
+}
+
+impl Default for SelectRules {
+  fn default() -> Self {
+    Self {
+      small_file_threshold: DEFAULT_SMALL_FILE_THRESHOLD,
+      sample_keywords: vec!["sample".into(), "preview".into(), "trailer".into()],
+      advertisement_keywords: vec![],
+      keep_subtitles: true,
+      keep_images: false,
+      keep_archives: false,
+    }
+  }
+}
+
+impl SelectRules {
+  fn wants(&self, kind: FileKind, size: i64) -> bool {
+    match kind {
+      FileKind::MainVideo | FileKind::MultiPart => true,
+      FileKind::Subtitle => self.keep_subtitles,
+      FileKind::Image => self.keep_images,
+      FileKind::Archive => self.keep_archives,
+      FileKind::Sample | FileKind::Advertisement => false,
+      FileKind::Other => size >= self.small_file_threshold,
+    }
+  }
+}
+
+fn contains_keyword(name: &str, keywords: &[String]) -> bool {
+  let name = name.to_lowercase();
+  keywords
+    .iter()
+    .any(|keyword| !keyword.is_empty() && name.contains(&keyword.to_lowercase()))
+}
+
+/// 对种子中的文件分类
+///
+/// 足够大的视频为正片，同一番号有多个正片时为分段；没有正片时，最大的文件视为正片。
+fn classify_files(contents: &[TorrentContent], rules: &SelectRules) -> Vec<FileKind> {
+  let mut kinds = contents
+    .iter()
+    .map(|content| {
+      let (_, stem, ext) = split_path(&content.name);
+      let ext = ext.map(|ext| ext.to_lowercase()).unwrap_or_default();
+
+      if contains_keyword(stem, &rules.advertisement_keywords)
+        || ADVERTISEMENT_EXTENSIONS.contains(&ext.as_str())
+      {
+        FileKind::Advertisement
+      } else if SUBTITLE_EXTENSIONS.contains(&ext.as_str()) {
+        FileKind::Subtitle
+      } else if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+        FileKind::Image
+      } else if ARCHIVE_EXTENSIONS.contains(&ext.as_str()) {
+        FileKind::Archive
+      } else if is_video(&content.name) {
+        if contains_keyword(&content.name, &rules.sample_keywords) {
+          FileKind::Sample
+        } else if content.size < rules.small_file_threshold {
+          FileKind::Advertisement
+        } else {
+          FileKind::MainVideo
+        }
+      } else {
+        FileKind::Other
+      }
+    })
+    .collect::<Vec<_>>();
+
+  let mut codes = HashMap::<String, Vec<usize>>::new();
+
+  for (i, content) in contents.iter().enumerate() {
+    if kinds[i] == FileKind::MainVideo {
+      let stem = split_path(&content.name).1.to_string();
+
+      if let Some(code) = get_movie_code(&stem) {
+        codes.entry(code).or_default().push(i);
+      }
+    }
+  }
+
+  for indexes in codes.into_values().filter(|indexes| indexes.len() > 1) {
+    for i in indexes {
+      kinds[i] = FileKind::MultiPart;
+    }
+  }
+
+  let has_main = kinds
+    .iter()
+    .any(|kind| matches!(kind, FileKind::MainVideo | FileKind::MultiPart));
+
+  if !has_main
+    && let Some((i, _)) = contents
+      .iter()
+      .enumerate()
+      .max_by_key(|(_, content)| content.size)
+  {
+    kinds[i] = FileKind::MainVideo;
+  }
+
+  kinds
+}
+
+/// 获取自动选择文件的规则
+#[tauri::command]
+#[specta::specta]
+pub fn get_select_rules() -> crate::error::Result<SelectRules> {
+  match get_setting(SELECT_RULES_KEY)? {
+    Some(rules) => Ok(rules),
+    None => Ok(SelectRules {
+      small_file_threshold: get_setting(SMALL_FILE_THRESHOLD_KEY)?
+        .unwrap_or(DEFAULT_SMALL_FILE_THRESHOLD),
+      ..Default::default()
+    }),
+  }
+}
+
+/// 保存自动选择文件的规则
+#[tauri::command]
+#[specta::specta]
+pub fn set_select_rules(rules: SelectRules) -> crate::error::Result<()> {
+  set_setting(SELECT_RULES_KEY, rules)
+}
+
+/// 自动选择要下载的文件，返回更新优先级后的文件列表
+#[tauri::command]
+#[specta::specta]
+pub async fn auto_select_files(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+) -> Result<Vec<TorrentContent>> {
+  let rules = get_select_rules()?;
+  let state = state.lock().await?;
+  let mut contents = state.torrent_contents(&hash).await?;
+  let kinds = classify_files(&contents, &rules);
+
+  let mut wanted = vec![];
+  let mut unwanted = vec![];
+
+  for (content, kind) in contents.iter_mut().zip(kinds) {
+    if rules.wants(kind, content.size) {
+      content.priority = PRIORITY_NORMAL;
+      wanted.push(content.index);
+    } else {
+      content.priority = PRIORITY_DO_NOT_DOWNLOAD;
+      unwanted.push(content.index);
+    }
+  }
+
+  if !wanted.is_empty() {
+    state.file_priority(&hash, &wanted, PRIORITY_NORMAL).await?;
+  }
+
+  if !unwanted.is_empty() {
+    state
+      .file_priority(&hash, &unwanted, PRIORITY_DO_NOT_DOWNLOAD)
+      .await?;
+  }
+
+  Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  const MB: i64 = 1024 * 1024;
+
+  fn contents(files: &[(&str, i64)]) -> Vec<TorrentContent> {
+    files
+      .iter()
+      .enumerate()
+      .map(|(index, (name, size))| TorrentContent {
+        index,
+        name: name.to_string(),
+        size: *size,
+        progress: 0.0,
+        priority: PRIORITY_NORMAL,
+        piece_range: vec![],
+        availability: 0.0,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_classify_files() {
+    let rules = SelectRules::default();
+
+    let files = contents(&[
+      ("SSIS-001/SSIS-001.mp4", 5000 * MB),
+      ("SSIS-001/SSIS-001.srt", MB / 10),
+      ("SSIS-001/sample/SSIS-001-sample.mp4", 300 * MB),
+      ("SSIS-001/最新地址.mp4", 20 * MB),
+      ("SSIS-001/社区.url", 1),
+      ("SSIS-001/cover.jpg", MB),
+      ("SSIS-001/extras.zip", 50 * MB),
+      ("SSIS-001/readme.nfo", 1024),
+    ]);
+    assert_eq!(
+      classify_files(&files, &rules),
+      vec![
+        FileKind::MainVideo,
+        FileKind::Subtitle,
+        FileKind::Sample,
+        FileKind::Advertisement,
+        FileKind::Advertisement,
+        FileKind::Image,
+        FileKind::Archive,
+        FileKind::Other,
+      ]
+    );
+
+    let files = contents(&[
+      ("ABP-123/ABP-123-1.mkv", 2000 * MB),
+      ("ABP-123/ABP-123-2.mkv", 1800 * MB),
+      ("ABP-123/ABP-123.jpg", MB),
+    ]);
+    assert_eq!(
+      classify_files(&files, &rules),
+      vec![FileKind::MultiPart, FileKind::MultiPart, FileKind::Image]
+    );
+  }
+
+  #[test]
+  fn test_classify_files_without_main_video() {
+    let rules = SelectRules {
+      small_file_threshold: 500 * MB,
+      advertisement_keywords: vec!["最新地址".into()],
+      ..Default::default()
+    };
+
+    let files = contents(&[
+      ("FC2-PPV-1234567.mp4", 300 * MB),
+      ("最新地址.mp4", 10 * MB),
+      ("cover.png", MB),
+    ]);
+    assert_eq!(
+      classify_files(&files, &rules),
+      vec![
+        FileKind::MainVideo,
+        FileKind::Advertisement,
+        FileKind::Image,
+      ]
+    );
+  }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+
+use tauri::State;
+
+use super::{
+  Category, MainData, QBittorrentState, ServerState, TorrentInfo, TorrentState, error::Result,
+};
+
+/// 用增量中存在的字段覆盖原有字段
+macro_rules! merge_fields {
+  ($target:expr, $delta:expr, [$($field:ident),* $(,)?]) => {
+    $(
+      if $delta.$field.is_some() {
+        $target.$field = $delta.$field;
+      }
+    )*
+  };
+}
+
+pub(super) use merge_fields;
+
+impl TorrentInfo {
+  fn merge(&mut self, delta: TorrentInfo) {
+    merge_fields!(
+      self,
+      delta,
+      [
+        added_on,
+        amount_left,
+        auto_tmm,
+        availability,
+        category,
+        completed,
+        completion_on,
+        dl_limit,
+        dlspeed,
+        downloaded,
+        downloaded_session,
+        eta,
+        f_l_piece_prio,
+        force_start,
+        hash,
+        infohash_v1,
+        infohash_v2,
+        last_activity,
+        magnet_uri,
+        max_ratio,
+        max_seeding_time,
+        name,
+        num_complete,
+        num_incomplete,
+        num_leechs,
+        num_seeds,
+        priority,
+        progress,
+        ratio,
+        ratio_limit,
+        save_path,
+        seeding_time_limit,
+        seen_complete,
+        seq_dl,
+        size,
+        state,
+        super_seeding,
+        tags,
+        time_active,
+        total_size,
+        tracker,
+        up_limit,
+        uploaded,
+        uploaded_session,
+        upspeed,
+      ]
+    );
+  }
+}
+
+impl ServerState {
+  fn merge(&mut self, delta: ServerState) {
+    merge_fields!(
+      self,
+      delta,
+      [
+        alltime_dl,
+        alltime_ul,
+        average_time_queue,
+        connection_status,
+        dht_nodes,
+        dl_info_data,
+        dl_info_speed,
+        dl_rate_limit,
+        free_space_on_disk,
+        global_ratio,
+        queued_io_jobs,
+        queueing,
+        read_cache_hits,
+        read_cache_overload,
+        refresh_interval,
+        total_buffers_size,
+        total_peer_connections,
+        total_queued_size,
+        total_wasted_session,
+        up_info_data,
+        up_info_speed,
+        up_rate_limit,
+        use_alt_speed_limits,
+        use_subcategories,
+        write_cache_overload,
+      ]
+    );
+  }
+}
+
+impl Category {
+  fn merge(&mut self, delta: Category) {
+    merge_fields!(self, delta, [name, save_path]);
+  }
+}
+
+/// 合并增量时产生的变化
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncChange {
+  TorrentAdded(String),
+  /// 已有种子的字段改变
+  TorrentUpdated(String),
+  TorrentRemoved(String),
+  /// 种子哈希与之前的状态
+  TorrentStateChanged(String, Option<TorrentState>),
+  TorrentCompleted(String),
+  ServerStateChanged,
+}
+
+/// 合并后的同步数据
+#[derive(Debug, Default)]
+pub struct SyncData {
+  /// Response ID of the last applied update
+  pub rid: u32,
+  pub server_state: ServerState,
+  pub torrents: HashMap<String, TorrentInfo>,
+  pub categories: HashMap<String, Category>,
+  pub tags: Vec<String>,
+}
+
+impl SyncData {
+  /// 应用 `sync/maindata` 返回的增量，返回其中的变化
+  pub fn apply(&mut self, delta: MainData) -> Vec<SyncChange> {
+    let mut changes = vec![];
+    let mut previous = HashMap::new();
+
+    if delta.full_update {
+      // 保留之前的种子，以便与完整数据比较
+      previous = std::mem::take(&mut self.torrents);
+      *self = Self::default();
+    }
+
+    self.rid = delta.rid;
+
+    if let Some(server_state) = delta.server_state {
+      self.server_state.merge(server_state);
+      changes.push(SyncChange::ServerStateChanged);
+    }
+
+    for (hash, info) in delta.torrents.unwrap_or_default() {
+      let existed = self
+        .torrents
+        .remove(&hash)
+        .or_else(|| previous.remove(&hash));
+
+      if let Some(mut existed) = existed {
+        let old_state = existed.state.clone();
+        let old_progress = existed.progress.unwrap_or_default();
+        existed.merge(info);
+        changes.push(SyncChange::TorrentUpdated(hash.clone()));
+
+        if existed.state != old_state {
+          changes.push(SyncChange::TorrentStateChanged(hash.clone(), old_state));
+        }
+
+        if old_progress < 1.0 && existed.progress.unwrap_or_default() >= 1.0 {
+          changes.push(SyncChange::TorrentCompleted(hash.clone()));
+        }
+
+        self.torrents.insert(hash, existed);
+      } else {
+        changes.push(SyncChange::TorrentAdded(hash.clone()));
+        self.torrents.insert(hash, info);
+      }
+    }
+
+    for hash in previous.into_keys() {
+      changes.push(SyncChange::TorrentRemoved(hash));
+    }
+
+    for hash in delta.torrents_removed.unwrap_or_default() {
+      if self.torrents.remove(&hash).is_some() {
+        changes.push(SyncChange::TorrentRemoved(hash));
+      }
+    }
+
+    for (name, category) in delta.categories.unwrap_or_default() {
+      if let Some(existed) = self.categories.get_mut(&name) {
+        existed.merge(category);
+      } else {
+        self.categories.insert(name, category);
+      }
+    }
+
+    for name in delta.categories_removed.unwrap_or_default() {
+      self.categories.remove(&name);
+    }
+
+    for tag in delta.tags.unwrap_or_default() {
+      if !self.tags.contains(&tag) {
+        self.tags.push(tag);
+      }
+    }
+
+    if let Some(removed) = delta.tags_removed {
+      self.tags.retain(|tag| !removed.contains(tag));
+    }
+
+    changes
+  }
+
+  /// 以完整更新的形式导出当前数据
+  pub fn snapshot(&self) -> MainData {
+    MainData {
+      full_update: true,
+      rid: self.rid,
+      server_state: Some(self.server_state.clone()),
+      torrents: Some(self.torrents.clone()),
+      torrents_removed: None,
+      categories: Some(self.categories.clone()),
+      categories_removed: None,
+      tags: Some(self.tags.clone()),
+      tags_removed: None,
+    }
+  }
+}
+
+/// 获取合并后的主要数据
+#[tauri::command]
+#[specta::specta]
+pub async fn get_main_data_snapshot(state: State<'_, QBittorrentState>) -> Result<MainData> {
+  let state = state.lock().await?;
+  Ok(state.sync.snapshot())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn main_data(json: &str) -> MainData {
+    serde_json::from_str(json).unwrap()
+  }
+
+  #[test]
+  fn test_apply() {
+    let mut data = SyncData::default();
+    let changes = data.apply(main_data(
+      r#"{
+        "full_update": true,
+        "rid": 1,
+        "server_state": { "dl_info_speed": 100, "refresh_interval": 1500 },
+        "torrents": {
+          "a": { "name": "ABC-123", "progress": 0.5, "tags": "scraped" },
+          "b": { "name": "DEF-456", "progress": 1.0 }
+        },
+        "categories": { "movies": { "name": "movies", "savePath": "/movies" } },
+        "tags": ["scraped", "no-code"]
+      }"#,
+    ));
+    assert_eq!(changes.len(), 3);
+    assert!(changes.contains(&SyncChange::TorrentAdded("a".to_string())));
+    assert!(changes.contains(&SyncChange::TorrentAdded("b".to_string())));
+
+    let changes = data.apply(main_data(
+      r#"{
+        "rid": 2,
+        "server_state": { "dl_info_speed": 200 },
+        "torrents": { "a": { "progress": 1.0, "state": "uploading" } },
+        "torrents_removed": ["b"],
+        "categories": { "movies": { "savePath": "/data/movies" } },
+        "tags_removed": ["no-code"]
+      }"#,
+    ));
+    assert_eq!(
+      changes,
+      vec![
+        SyncChange::ServerStateChanged,
+        SyncChange::TorrentUpdated("a".to_string()),
+        SyncChange::TorrentStateChanged("a".to_string(), None),
+        SyncChange::TorrentCompleted("a".to_string()),
+        SyncChange::TorrentRemoved("b".to_string()),
+      ]
+    );
+
+    assert_eq!(data.rid, 2);
+    assert_eq!(data.server_state.dl_info_speed, Some(200));
+    assert_eq!(data.server_state.refresh_interval, Some(1500));
+    assert_eq!(data.torrents.len(), 1);
+
+    let torrent = &data.torrents["a"];
+    assert_eq!(torrent.name.as_deref(), Some("ABC-123"));
+    assert_eq!(torrent.progress, Some(1.0));
+    assert_eq!(torrent.state, Some(TorrentState::Uploading));
+    assert_eq!(torrent.tags, Some(vec!["scraped".to_string()]));
+
+    let category = &data.categories["movies"];
+    assert_eq!(category.name.as_deref(), Some("movies"));
+    assert_eq!(category.save_path.as_deref(), Some("/data/movies"));
+    assert_eq!(data.tags, vec!["scraped".to_string()]);
+
+    let changes = data.apply(main_data(r#"{ "full_update": true, "rid": 3 }"#));
+    assert_eq!(changes, vec![SyncChange::TorrentRemoved("a".to_string())]);
+    assert_eq!(data.rid, 3);
+    assert!(data.torrents.is_empty());
+    assert!(data.categories.is_empty());
+    assert!(data.tags.is_empty());
+  }
+}
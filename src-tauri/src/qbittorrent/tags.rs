@@ -0,0 +1,123 @@
+use serde::{Deserialize, Deserializer};
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+/// 拆分以逗号连接的标签
+fn split_tags(tags: &str) -> Vec<String> {
+  tags
+    .split(',')
+    .map(str::trim)
+    .filter(|tag| !tag.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+pub(super) fn deserialize_tags<'de, D>(
+  deserializer: D,
+) -> std::result::Result<Option<Vec<String>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let tags: Option<String> = Option::deserialize(deserializer)?;
+  Ok(tags.map(|tags| split_tags(&tags)))
+}
+
+/// 获取标签
+#[tauri::command]
+#[specta::specta]
+pub async fn get_tags(state: State<'_, QBittorrentState>) -> Result<Vec<String>> {
+  let state = state.lock().await?;
+  state.get("torrents", "tags", None::<&()>).await
+}
+
+/// 创建标签
+#[tauri::command]
+#[specta::specta]
+pub async fn create_tags(state: State<'_, QBittorrentState>, tags: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "createTags",
+      &[("tags", tags.join(",").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除标签
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_tags(state: State<'_, QBittorrentState>, tags: Vec<String>) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "deleteTags",
+      &[("tags", tags.join(",").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 为种子添加标签
+#[tauri::command]
+#[specta::specta]
+pub async fn add_tags(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  tags: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "addTags",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("tags", tags.join(",").as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 移除种子的标签
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_tags(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  tags: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "removeTags",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("tags", tags.join(",").as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_split_tags() {
+    assert_eq!(split_tags(""), Vec::<String>::new());
+    assert_eq!(split_tags("scraped"), vec!["scraped".to_string()]);
+    assert_eq!(
+      split_tags("no-code, scraped"),
+      vec!["no-code".to_string(), "scraped".to_string()]
+    );
+  }
+}
@@ -0,0 +1,83 @@
+use tauri::State;
+
+use super::{QBittorrentState, error::Result};
+
+/// 切换顺序下载
+#[tauri::command]
+#[specta::specta]
+pub async fn toggle_sequential_download(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "toggleSequentialDownload",
+      &[("hashes", hashes.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 切换优先下载首尾文件块
+#[tauri::command]
+#[specta::specta]
+pub async fn toggle_first_last_piece_prio(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "toggleFirstLastPiecePrio",
+      &[("hashes", hashes.join("|").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置强制开始
+#[tauri::command]
+#[specta::specta]
+pub async fn set_force_start(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  value: bool,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setForceStart",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("value", value.to_string().as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 设置超级做种
+#[tauri::command]
+#[specta::specta]
+pub async fn set_super_seeding(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  value: bool,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "setSuperSeeding",
+      &[
+        ("hashes", hashes.join("|").as_str()),
+        ("value", value.to_string().as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
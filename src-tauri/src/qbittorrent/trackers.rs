@@ -0,0 +1,134 @@
+use log::debug;
+use tauri::State;
+
+use super::{QBittorrentState, QBittorrentStateInner, TorrentState, error::Result};
+use crate::settings::{get_setting, set_setting};
+
+const TRACKER_LIST_KEY: &str = "trackerList";
+
+async fn add_trackers_to(state: &QBittorrentStateInner, hash: &str, urls: &[String]) -> Result<()> {
+  state
+    .post(
+      "torrents",
+      "addTrackers",
+      &[("hash", hash), ("urls", urls.join("\n").as_str())],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 添加 Tracker
+#[tauri::command]
+#[specta::specta]
+pub async fn add_trackers(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  urls: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+
+  for hash in hashes {
+    add_trackers_to(&state, &hash, &urls).await?;
+  }
+
+  Ok(())
+}
+
+/// 修改 Tracker
+#[tauri::command]
+#[specta::specta]
+pub async fn edit_tracker(
+  state: State<'_, QBittorrentState>,
+  hash: String,
+  orig_url: String,
+  new_url: String,
+) -> Result<()> {
+  let state = state.lock().await?;
+  state
+    .post(
+      "torrents",
+      "editTracker",
+      &[
+        ("hash", hash.as_str()),
+        ("origUrl", orig_url.as_str()),
+        ("newUrl", new_url.as_str()),
+      ],
+    )
+    .await?;
+  Ok(())
+}
+
+/// 删除 Tracker
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_trackers(
+  state: State<'_, QBittorrentState>,
+  hashes: Vec<String>,
+  urls: Vec<String>,
+) -> Result<()> {
+  let state = state.lock().await?;
+  let urls = urls.join("|");
+
+  for hash in hashes {
+    state
+      .post(
+        "torrents",
+        "removeTrackers",
+        &[("hash", hash.as_str()), ("urls", urls.as_str())],
+      )
+      .await?;
+  }
+
+  Ok(())
+}
+
+/// 获取 Tracker 列表设置
+#[tauri::command]
+#[specta::specta]
+pub fn get_tracker_list() -> crate::error::Result<Vec<String>> {
+  Ok(get_setting(TRACKER_LIST_KEY)?.unwrap_or_default())
+}
+
+/// 保存 Tracker 列表设置
+#[tauri::command]
+#[specta::specta]
+pub fn set_tracker_list(trackers: Vec<String>) -> crate::error::Result<()> {
+  let trackers = trackers
+    .into_iter()
+    .map(|url| url.trim().to_string())
+    .filter(|url| !url.is_empty())
+    .collect::<Vec<_>>();
+  set_setting(TRACKER_LIST_KEY, trackers)
+}
+
+/// 为所有卡住或正在获取元数据的种子添加 Tracker 列表，返回添加了 Tracker 的种子哈希
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_tracker_list(state: State<'_, QBittorrentState>) -> Result<Vec<String>> {
+  let trackers = get_tracker_list()?;
+
+  if trackers.is_empty() {
+    return Ok(vec![]);
+  }
+
+  let state = state.lock().await?;
+  let hashes = state
+    .torrents_info(None)
+    .await?
+    .into_iter()
+    .filter(|info| {
+      matches!(
+        info.state,
+        Some(TorrentState::StalledDL | TorrentState::MetaDL)
+      )
+    })
+    .filter_map(|info| info.hash)
+    .collect::<Vec<_>>();
+
+  for hash in &hashes {
+    debug!("Adding trackers to {}", hash);
+    add_trackers_to(&state, hash, &trackers).await?;
+  }
+
+  Ok(hashes)
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tauri_plugin_store::StoreExt;
+
+use crate::{
+  app_handle::get_app_handle,
+  error::{Error, IntoResult, Result},
+};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// 与前端 `useStore` 相同的存储格式
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredValue<T> {
+  value: T,
+}
+
+/// 读取设置
+pub fn get_setting<T: DeserializeOwned>(key: &str) -> Result<Option<T>> {
+  let app = get_app_handle().ok_or(Error(anyhow::anyhow!("App handle not found")))?;
+  let store = app.store(SETTINGS_FILE).into_result()?;
+
+  if let Some(value) = store.get(key) {
+    let value: StoredValue<T> = serde_json::from_value(value).into_result()?;
+    Ok(Some(value.value))
+  } else {
+    Ok(None)
+  }
+}
+
+/// 保存设置
+pub fn set_setting<T: Serialize>(key: &str, value: T) -> Result<()> {
+  let app = get_app_handle().ok_or(Error(anyhow::anyhow!("App handle not found")))?;
+  let store = app.store(SETTINGS_FILE).into_result()?;
+  let value = serde_json::to_value(StoredValue { value }).into_result()?;
+  store.set(key, value);
+  store.save().into_result()
+}
@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::error::{Result, err};
+
+/// Bencode 值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+  Int(i64),
+  Bytes(Vec<u8>),
+  List(Vec<Value>),
+  Dict(BTreeMap<Vec<u8>, Value>),
+}
+
+impl Value {
+  pub fn get(&self, key: &str) -> Option<&Value> {
+    match self {
+      Self::Dict(dict) => dict.get(key.as_bytes()),
+      _ => None,
+    }
+  }
+
+  pub fn as_int(&self) -> Option<i64> {
+    match self {
+      Self::Int(value) => Some(*value),
+      _ => None,
+    }
+  }
+
+  pub fn as_bytes(&self) -> Option<&[u8]> {
+    match self {
+      Self::Bytes(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// 按 UTF-8 解码，无效字符会被替换
+  pub fn as_str(&self) -> Option<String> {
+    self
+      .as_bytes()
+      .map(|bytes| String::from_utf8_lossy(bytes).to_string())
+  }
+
+  pub fn as_list(&self) -> Option<&[Value]> {
+    match self {
+      Self::List(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  pub fn as_dict(&self) -> Option<&BTreeMap<Vec<u8>, Value>> {
+    match self {
+      Self::Dict(value) => Some(value),
+      _ => None,
+    }
+  }
+}
+
+/// 解码结果
+#[derive(Debug)]
+pub struct Decoded {
+  pub value: Value,
+  /// 顶层字典中 `info` 的原始字节范围，用于计算 info hash
+  pub info_range: Option<Range<usize>>,
+}
+
+struct Decoder<'a> {
+  data: &'a [u8],
+  pos: usize,
+  depth: usize,
+  info_range: Option<Range<usize>>,
+}
+
+/// 最大嵌套深度，防止恶意文件导致栈溢出
+const MAX_DEPTH: usize = 64;
+
+impl Decoder<'_> {
+  fn peek(&self) -> Result<u8> {
+    match self.data.get(self.pos) {
+      Some(byte) => Ok(*byte),
+      None => err("Unexpected end of bencode data"),
+    }
+  }
+
+  fn expect(&mut self, byte: u8) -> Result<()> {
+    if self.peek()? != byte {
+      return err(format!(
+        "Expected '{}' at position {}",
+        byte as char, self.pos
+      ));
+    }
+
+    self.pos += 1;
+    Ok(())
+  }
+
+  /// 读取到 `end` 为止的数字，不包括 `end`
+  fn read_number(&mut self, end: u8) -> Result<i64> {
+    let start = self.pos;
+
+    while self.peek()? != end {
+      self.pos += 1;
+    }
+
+    let text = std::str::from_utf8(&self.data[start..self.pos]).unwrap_or_default();
+    self.pos += 1;
+
+    match text.parse() {
+      Ok(number) => Ok(number),
+      Err(_) => err(format!("Invalid number at position {}", start)),
+    }
+  }
+
+  fn read_bytes(&mut self) -> Result<Vec<u8>> {
+    let len = self.read_number(b':')?;
+    let end = usize::try_from(len)
+      .ok()
+      .and_then(|len| self.pos.checked_add(len))
+      .filter(|end| *end <= self.data.len());
+
+    match end {
+      Some(end) => {
+        let bytes = self.data[self.pos..end].to_vec();
+        self.pos = end;
+        Ok(bytes)
+      }
+      None => err(format!("Invalid string length at position {}", self.pos)),
+    }
+  }
+
+  fn read_value(&mut self) -> Result<Value> {
+    if self.depth >= MAX_DEPTH {
+      return err("Bencode data is nested too deeply");
+    }
+
+    match self.peek()? {
+      b'i' => {
+        self.pos += 1;
+        Ok(Value::Int(self.read_number(b'e')?))
+      }
+      b'l' => {
+        self.pos += 1;
+        self.depth += 1;
+        let mut list = vec![];
+
+        while self.peek()? != b'e' {
+          list.push(self.read_value()?);
+        }
+
+        self.depth -= 1;
+        self.expect(b'e')?;
+        Ok(Value::List(list))
+      }
+      b'd' => {
+        self.pos += 1;
+        self.depth += 1;
+        let mut dict = BTreeMap::new();
+
+        while self.peek()? != b'e' {
+          let key = self.read_bytes()?;
+          let start = self.pos;
+          let value = self.read_value()?;
+
+          if self.depth == 1 && key == b"info" {
+            self.info_range = Some(start..self.pos);
+          }
+
+          dict.insert(key, value);
+        }
+
+        self.depth -= 1;
+        self.expect(b'e')?;
+        Ok(Value::Dict(dict))
+      }
+      b'0'..=b'9' => Ok(Value::Bytes(self.read_bytes()?)),
+      byte => err(format!(
+        "Unexpected '{}' at position {}",
+        byte as char, self.pos
+      )),
+    }
+  }
+}
+
+/// 解码 Bencode 数据
+pub fn decode(data: &[u8]) -> Result<Decoded> {
+  let mut decoder = Decoder {
+    data,
+    pos: 0,
+    depth: 0,
+    info_range: None,
+  };
+  let value = decoder.read_value()?;
+
+  Ok(Decoded {
+    value,
+    info_range: decoder.info_range,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_decode() {
+    let data = b"d3:agei42e4:infod4:name4:test6:lengthi-1ee4:listl1:a1:bee";
+    let decoded = decode(data).unwrap();
+
+    assert_eq!(decoded.value.get("age"), Some(&Value::Int(42)));
+    assert_eq!(
+      decoded.value.get("info").and_then(|info| info.get("name")),
+      Some(&Value::Bytes(b"test".to_vec()))
+    );
+    assert_eq!(
+      decoded.value.get("list"),
+      Some(&Value::List(vec![
+        Value::Bytes(b"a".to_vec()),
+        Value::Bytes(b"b".to_vec()),
+      ]))
+    );
+    assert_eq!(
+      &data[decoded.info_range.unwrap()],
+      b"d4:name4:test6:lengthi-1ee"
+    );
+
+    assert!(decode(b"d3:age").is_err());
+    assert!(decode(b"5:abc").is_err());
+    assert!(decode(b"x").is_err());
+  }
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use url::Url;
+
+use super::qbittorrent_hash;
+use crate::error::{IntoResult, Result, err};
+
+/// 磁力链接
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct Magnet {
+  /// 小写的 v1 info hash
+  pub info_hash_v1: Option<String>,
+  /// 小写的 v2 info hash
+  pub info_hash_v2: Option<String>,
+  /// 显示名称（dn）
+  pub display_name: Option<String>,
+  /// Tracker（tr）
+  pub trackers: Vec<String>,
+  /// 大小（xl）
+  pub length: Option<i64>,
+}
+
+impl Magnet {
+  /// qBittorrent 中使用的哈希，v2 种子为截断后的 v2 info hash
+  pub fn hash(&self) -> Option<String> {
+    qbittorrent_hash(self.info_hash_v1.as_deref(), self.info_hash_v2.as_deref())
+  }
+}
+
+/// 解码 RFC 4648 Base32
+fn decode_base32(text: &str) -> Option<Vec<u8>> {
+  let mut bytes = vec![];
+  let mut buffer = 0u64;
+  let mut bits = 0;
+
+  for c in text.trim_end_matches('=').chars() {
+    let value = match c.to_ascii_uppercase() {
+      c @ 'A'..='Z' => c as u64 - 'A' as u64,
+      c @ '2'..='7' => c as u64 - '2' as u64 + 26,
+      _ => return None,
+    };
+
+    buffer = (buffer << 5) | value;
+    bits += 5;
+
+    if bits >= 8 {
+      bits -= 8;
+      bytes.push((buffer >> bits) as u8);
+      buffer &= (1 << bits) - 1;
+    }
+  }
+
+  Some(bytes)
+}
+
+fn is_hex(text: &str, len: usize) -> bool {
+  text.len() == len && text.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// 将 btih 转换为小写的十六进制，支持十六进制与 Base32
+fn normalize_btih(hash: &str) -> Option<String> {
+  if is_hex(hash, 40) {
+    Some(hash.to_lowercase())
+  } else if hash.len() == 32 {
+    decode_base32(hash).map(hex::encode)
+  } else {
+    None
+  }
+}
+
+/// 将 btmh 转换为小写的十六进制，只支持 SHA-256 multihash（`1220` 前缀）
+fn normalize_btmh(hash: &str) -> Option<String> {
+  let hash = hash.to_lowercase();
+  let hash = hash.strip_prefix("1220")?;
+  is_hex(hash, 64).then(|| hash.to_string())
+}
+
+/// 解析磁力链接
+pub fn parse_magnet(uri: &str) -> Result<Magnet> {
+  let url = Url::parse(uri.trim()).into_result()?;
+
+  if url.scheme() != "magnet" {
+    return err(format!("Not a magnet link: {}", uri));
+  }
+
+  let mut magnet = Magnet {
+    info_hash_v1: None,
+    info_hash_v2: None,
+    display_name: None,
+    trackers: vec![],
+    length: None,
+  };
+
+  for (key, value) in url.query_pairs() {
+    // 多个 xt 时参数名可能为 xt.1、xt.2
+    match key.split('.').next().unwrap_or_default() {
+      "xt" => {
+        if let Some(hash) = value.strip_prefix("urn:btih:") {
+          magnet.info_hash_v1 = normalize_btih(hash);
+        } else if let Some(hash) = value.strip_prefix("urn:btmh:") {
+          magnet.info_hash_v2 = normalize_btmh(hash);
+        }
+      }
+      "dn" => magnet.display_name = Some(value.to_string()),
+      "tr" => magnet.trackers.push(value.to_string()),
+      "xl" => magnet.length = value.parse().ok(),
+      _ => {}
+    }
+  }
+
+  if magnet.info_hash_v1.is_none() && magnet.info_hash_v2.is_none() {
+    return err(format!("Invalid info hash: {}", uri));
+  }
+
+  Ok(magnet)
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_parse_magnet() {
+    let magnet = parse_magnet(
+      "magnet:?xt=urn:btih:C12FE1C06BBA254A9DC9F519B335AA7C1367A88A&dn=SSIS-001%20test&tr=http%3A%2F%2Fa%2Fann&tr=udp%3A%2F%2Fb%3A80&xl=1024",
+    )
+    .unwrap();
+    assert_eq!(
+      magnet,
+      Magnet {
+        info_hash_v1: Some("c12fe1c06bba254a9dc9f519b335aa7c1367a88a".to_string()),
+        info_hash_v2: None,
+        display_name: Some("SSIS-001 test".to_string()),
+        trackers: vec!["http://a/ann".to_string(), "udp://b:80".to_string()],
+        length: Some(1024),
+      }
+    );
+
+    let magnet = parse_magnet("magnet:?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK").unwrap();
+    assert_eq!(
+      magnet.hash(),
+      Some("c12fe1c06bba254a9dc9f519b335aa7c1367a88a".to_string())
+    );
+
+    let magnet = parse_magnet(
+      "magnet:?xt=urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e",
+    )
+    .unwrap();
+    assert_eq!(magnet.info_hash_v1, None);
+    assert_eq!(
+      magnet.hash(),
+      Some("caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa".to_string())
+    );
+
+    assert!(parse_magnet("magnet:?xt=urn:btih:123").is_err());
+    assert!(parse_magnet("https://example.com/a.torrent").is_err());
+  }
+}
@@ -0,0 +1,401 @@
+mod bencode;
+mod magnet;
+
+use bencode::{Value, decode};
+use magnet::Magnet;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use specta::Type;
+use tauri::State;
+
+use crate::{
+  db::{DbState, has_been_downloaded},
+  error::{IntoResult, Result, err},
+  qbittorrent::QBittorrentState,
+  scrape::get_movie_code,
+};
+
+pub use magnet::parse_magnet;
+
+/// 种子中的文件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct TorrentFile {
+  /// 文件序号，与 qBittorrent 中的序号一致
+  pub index: usize,
+  /// 包括种子名称的相对路径
+  pub path: String,
+  /// 文件大小（字节）
+  pub size: i64,
+}
+
+/// 种子文件预览
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TorrentPreview {
+  pub name: String,
+  /// v1 info hash，v2 种子为空
+  pub info_hash_v1: Option<String>,
+  /// v2 info hash，v1 种子为空
+  pub info_hash_v2: Option<String>,
+  pub files: Vec<TorrentFile>,
+  pub total_size: i64,
+  pub piece_length: Option<i64>,
+  pub private: bool,
+  pub trackers: Vec<String>,
+  pub comment: Option<String>,
+  pub created_by: Option<String>,
+  /// 创建时间（Unix epoch）
+  pub creation_date: Option<i64>,
+  /// 从名称中识别的番号
+  pub movie_code: Option<String>,
+  /// 之前下载完成的时间
+  pub downloaded_at: Option<i64>,
+}
+
+impl TorrentPreview {
+  /// qBittorrent 中使用的哈希，v2 种子为截断后的 v2 info hash
+  pub fn hash(&self) -> Option<String> {
+    qbittorrent_hash(self.info_hash_v1.as_deref(), self.info_hash_v2.as_deref())
+  }
+}
+
+/// 链接检查结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(tag = "kind", content = "value", rename_all = "camelCase")]
+pub enum LinkStatus {
+  New,
+  /// 已在 qBittorrent 中
+  InClient,
+  /// 之前下载过，值为下载完成的时间
+  DownloadedBefore(i64),
+  /// 无法解析的磁力链接
+  Invalid(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LinkVerdict {
+  pub link: String,
+  /// 磁力链接的解析结果，其他链接为空
+  pub magnet: Option<Magnet>,
+  pub movie_code: Option<String>,
+  pub status: LinkStatus,
+}
+
+/// qBittorrent 中使用的哈希，有 v1 info hash 时使用 v1，否则为截断到 40 位的 v2 info hash
+fn qbittorrent_hash(info_hash_v1: Option<&str>, info_hash_v2: Option<&str>) -> Option<String> {
+  info_hash_v1
+    .or_else(|| info_hash_v2.map(|hash| &hash[..40]))
+    .map(str::to_string)
+}
+
+/// 优先使用 `.utf-8` 后缀的字段
+fn get_str(dict: &Value, key: &str) -> Option<String> {
+  dict
+    .get(&format!("{}.utf-8", key))
+    .or_else(|| dict.get(key))
+    .and_then(Value::as_str)
+}
+
+fn join_path(name: &str, parts: &[String]) -> String {
+  let mut path = name.to_string();
+
+  for part in parts {
+    path.push('/');
+    path.push_str(part);
+  }
+
+  path
+}
+
+/// v1 种子的文件列表，跳过填充文件
+fn v1_files(info: &Value, name: &str) -> Option<Vec<(String, i64)>> {
+  if let Some(length) = info.get("length").and_then(Value::as_int) {
+    return Some(vec![(name.to_string(), length)]);
+  }
+
+  let files = info.get("files")?.as_list()?;
+  let mut result = vec![];
+
+  for file in files {
+    let is_padding = file
+      .get("attr")
+      .and_then(Value::as_str)
+      .is_some_and(|attr| attr.contains('p'));
+
+    if is_padding {
+      continue;
+    }
+
+    let parts = file
+      .get("path.utf-8")
+      .or_else(|| file.get("path"))
+      .and_then(Value::as_list)
+      .unwrap_or_default()
+      .iter()
+      .filter_map(Value::as_str)
+      .collect::<Vec<_>>();
+    let length = file
+      .get("length")
+      .and_then(Value::as_int)
+      .unwrap_or_default();
+    result.push((join_path(name, &parts), length));
+  }
+
+  Some(result)
+}
+
+/// v2 种子的文件树，文件节点为 `{"": {"length": ...}}`
+fn v2_files(tree: &Value, parents: &mut Vec<String>, result: &mut Vec<(Vec<String>, i64)>) {
+  let Some(dict) = tree.as_dict() else {
+    return;
+  };
+
+  for (key, child) in dict {
+    if key.is_empty() {
+      let length = child
+        .get("length")
+        .and_then(Value::as_int)
+        .unwrap_or_default();
+      result.push((parents.clone(), length));
+    } else {
+      parents.push(String::from_utf8_lossy(key).to_string());
+      v2_files(child, parents, result);
+      parents.pop();
+    }
+  }
+}
+
+fn trackers(torrent: &Value) -> Vec<String> {
+  let mut trackers = vec![];
+
+  let tiers = torrent
+    .get("announce-list")
+    .and_then(Value::as_list)
+    .unwrap_or_default();
+  let urls = torrent
+    .get("announce")
+    .and_then(Value::as_str)
+    .into_iter()
+    .chain(
+      tiers
+        .iter()
+        .filter_map(Value::as_list)
+        .flatten()
+        .filter_map(Value::as_str),
+    );
+
+  for url in urls {
+    if !url.is_empty() && !trackers.contains(&url) {
+      trackers.push(url);
+    }
+  }
+
+  trackers
+}
+
+/// 解析种子文件
+pub fn parse_torrent(data: &[u8]) -> Result<TorrentPreview> {
+  let decoded = decode(data)?;
+  let torrent = decoded.value;
+
+  let (Some(info), Some(info_range)) = (torrent.get("info"), decoded.info_range) else {
+    return err("Missing info dictionary");
+  };
+
+  let info_bytes = &data[info_range];
+  let name = get_str(info, "name").unwrap_or_default();
+  let is_v2 = info.get("meta version").and_then(Value::as_int) == Some(2);
+  let is_v1 = info.get("pieces").is_some();
+
+  let info_hash_v1 = is_v1.then(|| hex::encode(Sha1::digest(info_bytes)));
+  let info_hash_v2 = is_v2.then(|| hex::encode(Sha256::digest(info_bytes)));
+
+  let files = match v1_files(info, &name) {
+    Some(files) => files,
+    None => {
+      let mut files = vec![];
+
+      if let Some(tree) = info.get("file tree") {
+        v2_files(tree, &mut vec![], &mut files);
+      }
+
+      // 单文件种子的文件树只有一个与名称相同的文件
+      match files.as_slice() {
+        [(parts, length)] if parts.len() == 1 && parts[0] == name => {
+          vec![(name.clone(), *length)]
+        }
+        _ => files
+          .into_iter()
+          .map(|(parts, length)| (join_path(&name, &parts), length))
+          .collect(),
+      }
+    }
+  };
+
+  let files = files
+    .into_iter()
+    .enumerate()
+    .map(|(index, (path, size))| TorrentFile { index, path, size })
+    .collect::<Vec<_>>();
+
+  Ok(TorrentPreview {
+    total_size: files.iter().map(|file| file.size).sum(),
+    piece_length: info.get("piece length").and_then(Value::as_int),
+    private: info.get("private").and_then(Value::as_int) == Some(1),
+    trackers: trackers(&torrent),
+    comment: get_str(&torrent, "comment"),
+    created_by: get_str(&torrent, "created by"),
+    creation_date: torrent.get("creation date").and_then(Value::as_int),
+    movie_code: get_movie_code(&name),
+    downloaded_at: None,
+    name,
+    info_hash_v1,
+    info_hash_v2,
+    files,
+  })
+}
+
+/// 预览种子文件
+///
+/// 返回文件列表与哈希，并按番号或哈希检查之前是否下载过。
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_torrent_file(
+  state: State<'_, DbState>,
+  path: String,
+) -> Result<TorrentPreview> {
+  let data = tokio::fs::read(&path).await.into_result()?;
+  let mut preview = parse_torrent(&data)?;
+  preview.downloaded_at = has_been_downloaded(state, preview.name.clone(), preview.hash()).await?;
+  Ok(preview)
+}
+
+/// 检查链接是否已添加或下载过，多个链接以换行分隔
+///
+/// 磁力链接按 info hash 与当前种子列表比较，并按番号或哈希查找下载记录；其他链接只检查下载记录。
+#[tauri::command]
+#[specta::specta]
+pub async fn check_links(
+  qb_state: State<'_, QBittorrentState>,
+  db_state: State<'_, DbState>,
+  urls: String,
+) -> Result<Vec<LinkVerdict>> {
+  let hashes = qb_state.lock().await?.torrent_hashes().await?;
+  let mut verdicts = vec![];
+
+  for link in urls.lines().map(str::trim).filter(|link| !link.is_empty()) {
+    let (magnet, status) = if link.starts_with("magnet:") {
+      match parse_magnet(link) {
+        Ok(magnet) => (Some(magnet), LinkStatus::New),
+        Err(e) => (None, LinkStatus::Invalid(e.to_string())),
+      }
+    } else {
+      (None, LinkStatus::New)
+    };
+
+    let name = magnet
+      .as_ref()
+      .and_then(|magnet| magnet.display_name.clone())
+      .unwrap_or_else(|| link.to_string());
+    let hash = magnet.as_ref().and_then(Magnet::hash);
+    let movie_code = get_movie_code(&name);
+
+    let in_client = magnet.as_ref().is_some_and(|magnet| {
+      [&magnet.info_hash_v1, &magnet.info_hash_v2, &hash]
+        .into_iter()
+        .flatten()
+        .any(|hash| hashes.contains(hash))
+    });
+
+    let status = if in_client {
+      LinkStatus::InClient
+    } else if status == LinkStatus::New {
+      match has_been_downloaded(db_state.clone(), name, hash).await? {
+        Some(downloaded_at) => LinkStatus::DownloadedBefore(downloaded_at),
+        None => LinkStatus::New,
+      }
+    } else {
+      status
+    };
+
+    verdicts.push(LinkVerdict {
+      link: link.to_string(),
+      magnet,
+      movie_code,
+      status,
+    });
+  }
+
+  Ok(verdicts)
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  #[test]
+  fn test_parse_v1_torrent() {
+    let info = concat!(
+      "d5:filesl",
+      "d6:lengthi1000e4:pathl12:SSIS-001.mp4ee",
+      "d6:lengthi200e4:pathl4:subs12:SSIS-001.srtee",
+      "d4:attr1:p6:lengthi10e4:pathl4:.pad2:10ee",
+      "e4:name8:SSIS-00112:piece lengthi16384e6:pieces20:01234567890123456789e",
+    );
+    let data = format!(
+      "d8:announce12:http://a/ann13:announce-listll12:http://a/annel12:http://b/annee4:info{}e",
+      info
+    );
+
+    let preview = parse_torrent(data.as_bytes()).unwrap();
+    assert_eq!(
+      preview.info_hash_v1,
+      Some(hex::encode(Sha1::digest(info.as_bytes())))
+    );
+    assert_eq!(preview.info_hash_v2, None);
+    assert_eq!(preview.name, "SSIS-001");
+    assert_eq!(preview.movie_code, Some("SSIS-001".to_string()));
+    assert_eq!(preview.total_size, 1200);
+    assert_eq!(preview.trackers, vec!["http://a/ann", "http://b/ann"]);
+    assert_eq!(
+      preview.files,
+      vec![
+        TorrentFile {
+          index: 0,
+          path: "SSIS-001/SSIS-001.mp4".to_string(),
+          size: 1000,
+        },
+        TorrentFile {
+          index: 1,
+          path: "SSIS-001/subs/SSIS-001.srt".to_string(),
+          size: 200,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_v2_torrent() {
+    let info = concat!(
+      "d9:file treed8:SSIS-001d0:d6:lengthi5eeee",
+      "12:meta versioni2e4:name8:SSIS-00112:piece lengthi16384ee",
+    );
+    let data = format!("d4:info{}e", info);
+
+    let preview = parse_torrent(data.as_bytes()).unwrap();
+    let hash_v2 = hex::encode(Sha256::digest(info.as_bytes()));
+    assert_eq!(preview.info_hash_v1, None);
+    assert_eq!(preview.hash(), Some(hash_v2[..40].to_string()));
+    assert_eq!(preview.info_hash_v2, Some(hash_v2));
+    assert_eq!(
+      preview.files,
+      vec![TorrentFile {
+        index: 0,
+        path: "SSIS-001".to_string(),
+        size: 5,
+      }]
+    );
+  }
+}
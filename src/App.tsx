@@ -2,7 +2,7 @@ import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { debug, error } from '@tauri-apps/plugin-log';
 import { PrimeIcons } from 'primereact/api';
 import { Button } from 'primereact/button';
-import { useInterval, useLocalStorage, useTimeout } from 'primereact/hooks';
+import { useLocalStorage, useTimeout } from 'primereact/hooks';
 import { IconField } from 'primereact/iconfield';
 import { InputIcon } from 'primereact/inputicon';
 import { InputText } from 'primereact/inputtext';
@@ -11,22 +11,23 @@ import type { MenuItem } from 'primereact/menuitem';
 import { Sidebar } from 'primereact/sidebar';
 import { TabMenu } from 'primereact/tabmenu';
 import type { TreeTableExpandedKeysType, TreeTableSelectionKeysType } from 'primereact/treetable';
-import { diff, fork, max, unique } from 'radashi';
+import { diff, unique } from 'radashi';
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react';
 
-import { type MainData, type TorrentContent, commands } from './lib/bindings';
+import { type TorrentInfo, commands, events } from './lib/bindings';
 import cn from './lib/cn';
 import { formatSize, formatSpeed } from './lib/format';
 import makeTree from './lib/makeTree';
 import {
+  type RequiredServerState,
   type RequiredTorrentInfo,
   TorrentContentPriority,
   type TorrentFilter,
   defaultMainData,
+  fromSnapshot,
   getInfoHash,
   getInfoHashes,
   matchTorrent,
-  mergeMainData,
 } from './lib/qBittorrentTypes';
 import useClipboard from './lib/useClipboard';
 import { useStore } from './lib/useStore';
@@ -103,14 +104,12 @@ function App() {
   const [contentLoading, setContentLoading] = useState(true);
   const [showSettings, setShowSettings] = useState(false);
   const [showSidebar, setShowSidebar] = useState(false);
-  const [mainData, setMainDataRaw] = useState(defaultMainData);
-  const [smallFileThreshold] = useStore<number>('smallFileThreshold', 200 * 1024 * 1024);
+  const [mainData, setMainData] = useState(defaultMainData);
   const [watchClipboard] = useStore<boolean>('watchClipboard', false);
 
   const metas = useRef<RequiredTorrentInfo[]>([]);
   const torrents = Object.values(mainData.torrents);
   const currentTorrent = torrents.find((t) => t.infohash_v1 === currentHash);
-  const refreshInterval = mainData.server_state.refresh_interval;
   const totalSelected = selected.reduce((prev, t) => prev + t.size, 0);
 
   const setFilter = useCallback((filter: TorrentFilter) => {
@@ -118,10 +117,20 @@ function App() {
     setSelected([]);
   }, []);
 
-  const setMainData = useCallback(
-    (delta: MainData) => setMainDataRaw((data) => mergeMainData(data, delta)),
-    [],
-  );
+  const setTorrent = useCallback((hash: string, info: TorrentInfo) => {
+    setMainData((data) => ({
+      ...data,
+      torrents: { ...data.torrents, [hash]: info as RequiredTorrentInfo },
+    }));
+  }, []);
+
+  const removeTorrent = useCallback((hash: string) => {
+    setMainData((data) => {
+      const torrents = { ...data.torrents };
+      delete torrents[hash];
+      return { ...data, torrents };
+    });
+  }, []);
 
   const buttons = useMemo<MenuItem[]>(
     () => [
@@ -189,64 +198,6 @@ function App() {
     [],
   );
 
-  const autoSelect = useCallback(
-    async (hash: string) => {
-      const content = await commands.getTorrentContents(hash);
-      const [larges, smalls] = fork(content, (item) => item.size >= smallFileThreshold);
-
-      if (larges.length === 0) {
-        const largest = max(smalls, (item) => item.size);
-
-        if (largest) {
-          larges.push(largest);
-          remove(smalls, largest, (item) => item.index);
-        }
-      }
-
-      const promises = [
-        commands.setFilePriority(
-          hash,
-          larges.map((item) => item.index),
-          TorrentContentPriority.NORMAL,
-        ),
-      ];
-
-      if (smalls.length > 0) {
-        promises.push(
-          commands.setFilePriority(
-            hash,
-            smalls.map((item) => item.index),
-            TorrentContentPriority.DO_NOT_DOWNLOAD,
-          ),
-        );
-      }
-
-      await Promise.all(promises);
-
-      return [
-        ...larges.map(
-          (c) => ({ ...c, priority: TorrentContentPriority.NORMAL }) satisfies TorrentContent,
-        ),
-        ...smalls.map(
-          (c) =>
-            ({ ...c, priority: TorrentContentPriority.DO_NOT_DOWNLOAD }) satisfies TorrentContent,
-        ),
-      ];
-    },
-    [smallFileThreshold],
-  );
-
-  const refresh = useCallback(async () => {
-    try {
-      const data = await commands.getMainData();
-      setMainData(data);
-      setLoading(false);
-    } catch (e) {
-      console.warn('Re-login');
-      await commands.login(credentials.username, credentials.password);
-    }
-  }, [setMainData, setLoading, credentials]);
-
   useEffect(() => {
     const ts = Object.values(mainData.torrents);
     const hashes = getInfoHashes(ts);
@@ -258,9 +209,9 @@ function App() {
     metas.current = unique([...rest, ...newMetas], getInfoHash);
 
     if (noLongers.length > 0) {
-      Promise.all(noLongers.map((m) => autoSelect(m.infohash_v1))).catch(() => {});
+      Promise.all(noLongers.map((m) => commands.autoSelectFiles(m.infohash_v1))).catch(() => {});
     }
-  }, [mainData.torrents, autoSelect]);
+  }, [mainData.torrents]);
 
   const select = useCallback(
     async (node: TorrentNode) => {
@@ -285,8 +236,15 @@ function App() {
 
   useEffect(() => {
     commands
-      .initialize(credentials.url, null)
-      .then(() => commands.login(credentials.username, credentials.password))
+      .getActiveProfile()
+      .then(async (profile) => {
+        if (profile !== null) {
+          return true;
+        }
+
+        await commands.initialize(credentials.url, null);
+        return commands.login(credentials.username, credentials.password, null);
+      })
       .then((ok) => {
         setShowLogin(!ok);
         debug('Getting main data');
@@ -294,7 +252,9 @@ function App() {
       })
       .then((data) => {
         debug('Setting main data');
-        setMainData(data);
+        setMainData(fromSnapshot(data));
+        setLoading(false);
+        return commands.startSync();
       })
       .catch((e) => {
         error(`Failed to login: ${e}`);
@@ -302,18 +262,28 @@ function App() {
       });
   }, [credentials]);
 
-  useTimeout(() => setHideLogin(false), 1000);
+  useEffect(() => {
+    const unlistens = Promise.all([
+      events.torrentAdded.listen(({ payload }) => setTorrent(payload.hash, payload.info)),
+      events.torrentUpdated.listen(({ payload }) => setTorrent(payload.hash, payload.info)),
+      events.torrentRemoved.listen(({ payload }) => removeTorrent(payload.hash)),
+      events.serverStateChanged.listen(({ payload }) => {
+        setMainData((data) => ({
+          ...data,
+          server_state: { ...data.server_state, ...payload.server_state } as RequiredServerState,
+        }));
+      }),
+    ]);
+
+    return () => {
+      unlistens.then((fns) => fns.forEach((unlisten) => unlisten()));
+    };
+  }, [setTorrent, removeTorrent]);
 
-  useInterval(
-    () => {
-      refresh().catch(console.error);
-    },
-    refreshInterval,
-    !showLogin,
-  );
+  useTimeout(() => setHideLogin(false), 1000);
 
   const onClipboard = useCallback((text: string) => {
-    commands.addUrls(text);
+    commands.addUrls(text, null);
   }, []);
 
   useClipboard({
@@ -385,7 +355,7 @@ function App() {
           setShowAdd(false);
 
           if (urls) {
-            commands.addUrls(urls);
+            commands.addUrls(urls, null);
           }
         }}
       />
@@ -403,12 +373,11 @@ function App() {
         onUnselect={unselect}
         onMagnetToTorrent={async () => {
           setShowTorrent(false);
-          await commands.delete([currentHash]);
-          await commands.addUrls(`https://itorrents.org/torrent/${currentHash}.torrent`);
+          await commands.convertMagnetToTorrent(currentHash);
         }}
         onAutoSelect={async () => {
           if (commands) {
-            const content = await autoSelect(currentHash);
+            const content = await commands.autoSelectFiles(currentHash);
 
             const { nodes, selected, expanded } = makeTree(content);
             setNodes(nodes);
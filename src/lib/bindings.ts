@@ -8,144 +8,1473 @@
 export const commands = {
 /**
  * 添加文件
+ * 
+ * 未指定 `options` 时以停止状态添加，并创建根目录。
  */
-async addFiles(paths: string[]) : Promise<null> {
-    return await TAURI_INVOKE("add_files", { paths });
+async addFiles(paths: string[], options: AddTorrentOptions | null) : Promise<null> {
+    return await TAURI_INVOKE("add_files", { paths, options });
+},
+/**
+ * 添加 RSS 订阅
+ */
+async addRssFeed(url: string, path: string | null) : Promise<null> {
+    return await TAURI_INVOKE("add_rss_feed", { url, path });
+},
+/**
+ * 添加 RSS 文件夹，`path` 以 `\` 分隔
+ */
+async addRssFolder(path: string) : Promise<null> {
+    return await TAURI_INVOKE("add_rss_folder", { path });
+},
+/**
+ * 为种子添加标签
+ */
+async addTags(hashes: string[], tags: string[]) : Promise<null> {
+    return await TAURI_INVOKE("add_tags", { hashes, tags });
+},
+/**
+ * 添加 Tracker
+ */
+async addTrackers(hashes: string[], urls: string[]) : Promise<null> {
+    return await TAURI_INVOKE("add_trackers", { hashes, urls });
 },
 /**
  * 添加链接
+ * 
+ * 未指定 `options` 时创建根目录。
+ */
+async addUrls(urls: string, options: AddTorrentOptions | null) : Promise<null> {
+    return await TAURI_INVOKE("add_urls", { urls, options });
+},
+/**
+ * 为所有卡住或正在获取元数据的种子添加 Tracker 列表，返回添加了 Tracker 的种子哈希
+ */
+async applyTrackerList() : Promise<string[]> {
+    return await TAURI_INVOKE("apply_tracker_list");
+},
+/**
+ * 自动选择要下载的文件，返回更新优先级后的文件列表
+ */
+async autoSelectFiles(hash: string) : Promise<TorrentContent[]> {
+    return await TAURI_INVOKE("auto_select_files", { hash });
+},
+/**
+ * 移至队列底部
+ */
+async bottomPriority(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("bottom_priority", { hashes });
+},
+/**
+ * 检查链接是否已添加或下载过，多个链接以换行分隔
+ * 
+ * 磁力链接按 info hash 与当前种子列表比较，并按番号或哈希查找下载记录；其他链接只检查下载记录。
+ */
+async checkLinks(urls: string) : Promise<LinkVerdict[]> {
+    return await TAURI_INVOKE("check_links", { urls });
+},
+/**
+ * 对比两个服务器中的种子
+ */
+async compareTorrents(first: string, second: string) : Promise<TorrentComparison> {
+    return await TAURI_INVOKE("compare_torrents", { first, second });
+},
+/**
+ * 将磁力链接转换为种子文件，返回下载种子文件的地址
+ * 
+ * 依次尝试缓存地址，种子文件的 info hash 一致时才删除原来的种子并重新添加，保留分类、标签与保存路径。
+ * 下载种子文件时不锁定服务器的连接。
+ */
+async convertMagnetToTorrent(hash: string) : Promise<string> {
+    return await TAURI_INVOKE("convert_magnet_to_torrent", { hash });
+},
+/**
+ * 创建分类
+ */
+async createCategory(category: string, savePath: string | null) : Promise<null> {
+    return await TAURI_INVOKE("create_category", { category, savePath });
+},
+/**
+ * 创建标签
+ */
+async createTags(tags: string[]) : Promise<null> {
+    return await TAURI_INVOKE("create_tags", { tags });
+},
+/**
+ * 降低队列优先级
+ */
+async decreasePriority(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("decrease_priority", { hashes });
+},
+/**
+ * 删除
+ */
+async delete(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("delete", { hashes });
+},
+/**
+ * 删除搜索任务
+ */
+async deleteSearch(id: number) : Promise<null> {
+    return await TAURI_INVOKE("delete_search", { id });
+},
+/**
+ * 删除标签
+ */
+async deleteTags(tags: string[]) : Promise<null> {
+    return await TAURI_INVOKE("delete_tags", { tags });
+},
+async downloadImage(url: string) : Promise<string> {
+    return await TAURI_INVOKE("download_image", { url });
+},
+/**
+ * 编辑分类
+ */
+async editCategory(category: string, savePath: string) : Promise<null> {
+    return await TAURI_INVOKE("edit_category", { category, savePath });
+},
+/**
+ * 修改 Tracker
+ */
+async editTracker(hash: string, origUrl: string, newUrl: string) : Promise<null> {
+    return await TAURI_INVOKE("edit_tracker", { hash, origUrl, newUrl });
+},
+/**
+ * 启用或禁用搜索插件
+ */
+async enableSearchPlugin(names: string[], enable: boolean) : Promise<null> {
+    return await TAURI_INVOKE("enable_search_plugin", { names, enable });
+},
+/**
+ * 导出种子文件到文件夹，返回保存的文件路径
+ * 
+ * 文件以番号与刮削的标题命名，与已有文件或本次导出的文件同名时在名称后添加哈希的前 8 位。
+ */
+async exportTorrents(hashes: string[], folder: string) : Promise<string[]> {
+    return await TAURI_INVOKE("export_torrents", { hashes, folder });
+},
+/**
+ * 获取当前服务器配置 ID
+ */
+async getActiveProfile() : Promise<string | null> {
+    return await TAURI_INVOKE("get_active_profile");
+},
+/**
+ * 获取 qBittorrent 版本
+ */
+async getAppVersion() : Promise<string> {
+    return await TAURI_INVOKE("get_app_version");
+},
+/**
+ * 获取编译信息
+ */
+async getBuildInfo() : Promise<BuildInfo> {
+    return await TAURI_INVOKE("get_build_info");
+},
+/**
+ * 获取分类
+ */
+async getCategories() : Promise<{ [key in string]: Category }> {
+    return await TAURI_INVOKE("get_categories");
+},
+/**
+ * 获取主要数据
+ * 
+ * 与后台同步共用合并后的数据，同步到的变化同样以事件发送，返回完整数据。
+ */
+async getMainData() : Promise<MainData> {
+    return await TAURI_INVOKE("get_main_data");
+},
+/**
+ * 获取合并后的主要数据
+ */
+async getMainDataSnapshot() : Promise<MainData> {
+    return await TAURI_INVOKE("get_main_data_snapshot");
+},
+/**
+ * 获取日志，只返回 ID 大于 `last_known_id` 的日志
+ */
+async getMainLog(lastKnownId: number | null) : Promise<LogEntry[]> {
+    return await TAURI_INVOKE("get_main_log", { lastKnownId });
+},
+/**
+ * 获取用户日志，只返回 ID 大于 `last_known_id` 的日志
+ */
+async getPeerLog(lastKnownId: number | null) : Promise<PeerLogEntry[]> {
+    return await TAURI_INVOKE("get_peer_log", { lastKnownId });
+},
+/**
+ * 获取分块状态
+ * 
+ * 0：未下载；1：正在下载；2：已下载
+ */
+async getPieceStates(hash: string) : Promise<number[]> {
+    return await TAURI_INVOKE("get_piece_states", { hash });
+},
+/**
+ * 获取应用程序设置
+ */
+async getPreferences() : Promise<Preferences> {
+    return await TAURI_INVOKE("get_preferences");
+},
+/**
+ * 获取服务器配置列表
+ */
+async getProfiles() : Promise<Profile[]> {
+    return await TAURI_INVOKE("get_profiles");
+},
+/**
+ * 获取 RSS 订阅，`with_data` 为 `true` 时包含文章
+ */
+async getRssItems(withData: boolean) : Promise<{ [key in string]: RssItem }> {
+    return await TAURI_INVOKE("get_rss_items", { withData });
+},
+/**
+ * 获取匹配规则的文章，返回订阅名称与文章标题
+ */
+async getRssMatchingArticles(ruleName: string) : Promise<{ [key in string]: string[] }> {
+    return await TAURI_INVOKE("get_rss_matching_articles", { ruleName });
+},
+/**
+ * 获取自动下载规则
+ */
+async getRssRules() : Promise<{ [key in string]: RssRule }> {
+    return await TAURI_INVOKE("get_rss_rules");
+},
+/**
+ * 获取搜索插件
+ */
+async getSearchPlugins() : Promise<SearchPlugin[]> {
+    return await TAURI_INVOKE("get_search_plugins");
+},
+/**
+ * 获取搜索结果
+ */
+async getSearchResults(id: number, limit: number | null, offset: number | null) : Promise<SearchResults> {
+    return await TAURI_INVOKE("get_search_results", { id, limit, offset });
+},
+/**
+ * 获取搜索状态，未指定 `id` 时获取所有搜索任务
+ */
+async getSearchStatus(id: number | null) : Promise<SearchStatus[]> {
+    return await TAURI_INVOKE("get_search_status", { id });
+},
+/**
+ * 获取自动选择文件的规则
+ */
+async getSelectRules() : Promise<SelectRules> {
+    return await TAURI_INVOKE("get_select_rules");
+},
+/**
+ * 获取标签
+ */
+async getTags() : Promise<string[]> {
+    return await TAURI_INVOKE("get_tags");
+},
+/**
+ * 获取种子缓存地址模板
+ */
+async getTorrentCaches() : Promise<string[]> {
+    return await TAURI_INVOKE("get_torrent_caches");
+},
+/**
+ * 获取 Tracker 列表设置
+ */
+async getTrackerList() : Promise<string[]> {
+    return await TAURI_INVOKE("get_tracker_list");
+},
+/**
+ * 获取 WebUI API 版本
+ */
+async getWebapiVersion() : Promise<string> {
+    return await TAURI_INVOKE("get_webapi_version");
+},
+/**
+ * 获取番号
+ */
+async guessMovieCode(name: string) : Promise<string | null> {
+    return await TAURI_INVOKE("guess_movie_code", { name });
+},
+/**
+ * 获取种子内容
+ */
+async getTorrentContents(hash: string) : Promise<TorrentContent[]> {
+    return await TAURI_INVOKE("get_torrent_contents", { hash });
+},
+/**
+ * 获取种子的用户列表
+ * 
+ * 每个种子单独记录 `rid`，只请求增量并在后端合并，返回完整的用户列表。
+ */
+async getTorrentPeers(hash: string) : Promise<{ [key in string]: PeerInfo }> {
+    return await TAURI_INVOKE("get_torrent_peers", { hash });
+},
+/**
+ * 获取种子属性
+ */
+async getTorrentProperties(hash: string) : Promise<TorrentProperties> {
+    return await TAURI_INVOKE("get_torrent_properties", { hash });
+},
+/**
+ * 获取种子的 Tracker
+ */
+async getTorrentTrackers(hash: string) : Promise<Tracker[]> {
+    return await TAURI_INVOKE("get_torrent_trackers", { hash });
+},
+/**
+ * 获取种子的 Web 种子
+ */
+async getTorrentWebseeds(hash: string) : Promise<WebSeed[]> {
+    return await TAURI_INVOKE("get_torrent_webseeds", { hash });
+},
+/**
+ * 获取视频信息
+ */
+async getVideoInfo(name: string) : Promise<VideoInfo | null> {
+    return await TAURI_INVOKE("get_video_info", { name });
+},
+/**
+ * 之前是否下载过
+ */
+async hasBeenDownloaded(name: string, hash: string | null) : Promise<number | null> {
+    return await TAURI_INVOKE("has_been_downloaded", { name, hash });
+},
+/**
+ * 提高队列优先级
+ */
+async increasePriority(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("increase_priority", { hashes });
+},
+/**
+ * 设置不使用服务器配置时的 URL，并切换为不使用服务器配置
+ */
+async initialize(url: string, proxy: string | null) : Promise<null> {
+    return await TAURI_INVOKE("initialize", { url, proxy });
+},
+/**
+ * 安装搜索插件，`sources` 为插件的链接或文件路径
+ */
+async installSearchPlugin(sources: string[]) : Promise<null> {
+    return await TAURI_INVOKE("install_search_plugin", { sources });
+},
+/**
+ * 登录不使用服务器配置时的连接
+ * 
+ * `remember` 默认为 `true`，此时会在内存中保存凭据，以便会话过期后自动重新登录。
+ */
+async login(username: string, password: string, remember: boolean | null) : Promise<boolean> {
+    return await TAURI_INVOKE("login", { username, password, remember });
+},
+/**
+ * 标记为已下载
+ */
+async markAsDownloaded(name: string, hash: string | null, downloadedAt: number) : Promise<null> {
+    return await TAURI_INVOKE("mark_as_downloaded", { name, hash, downloadedAt });
+},
+/**
+ * 标记为已读，未指定 `article_id` 时标记订阅中的所有文章
+ */
+async markRssAsRead(itemPath: string, articleId: string | null) : Promise<null> {
+    return await TAURI_INVOKE("mark_rss_as_read", { itemPath, articleId });
+},
+/**
+ * 移动 RSS 订阅或文件夹
+ */
+async moveRssItem(itemPath: string, destPath: string) : Promise<null> {
+    return await TAURI_INVOKE("move_rss_item", { itemPath, destPath });
+},
+/**
+ * 预览种子文件
+ * 
+ * 返回文件列表与哈希，并按番号或哈希检查之前是否下载过。
+ */
+async previewTorrentFile(path: string) : Promise<TorrentPreview> {
+    return await TAURI_INVOKE("preview_torrent_file", { path });
+},
+/**
+ * 重新校验
+ */
+async recheck(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("recheck", { hashes });
+},
+/**
+ * 刷新 RSS 订阅或文件夹
+ */
+async refreshRssItem(itemPath: string) : Promise<null> {
+    return await TAURI_INVOKE("refresh_rss_item", { itemPath });
+},
+/**
+ * 删除分类
+ */
+async removeCategories(categories: string[]) : Promise<null> {
+    return await TAURI_INVOKE("remove_categories", { categories });
+},
+/**
+ * 删除服务器配置
+ */
+async removeProfile(profileId: string) : Promise<null> {
+    return await TAURI_INVOKE("remove_profile", { profileId });
+},
+/**
+ * 删除 RSS 订阅或文件夹
+ */
+async removeRssItem(path: string) : Promise<null> {
+    return await TAURI_INVOKE("remove_rss_item", { path });
+},
+/**
+ * 删除自动下载规则
+ */
+async removeRssRule(ruleName: string) : Promise<null> {
+    return await TAURI_INVOKE("remove_rss_rule", { ruleName });
+},
+/**
+ * 移除种子的标签
+ */
+async removeTags(hashes: string[], tags: string[]) : Promise<null> {
+    return await TAURI_INVOKE("remove_tags", { hashes, tags });
+},
+/**
+ * 删除 Tracker
+ */
+async removeTrackers(hashes: string[], urls: string[]) : Promise<null> {
+    return await TAURI_INVOKE("remove_trackers", { hashes, urls });
+},
+/**
+ * 重命名
+ */
+async rename(hash: string, name: string) : Promise<null> {
+    return await TAURI_INVOKE("rename", { hash, name });
+},
+/**
+ * 重命名种子中的文件
+ */
+async renameFile(hash: string, oldPath: string, newPath: string) : Promise<null> {
+    return await TAURI_INVOKE("rename_file", { hash, oldPath, newPath });
+},
+/**
+ * 将种子中的视频文件重命名为番号，返回重命名的文件
+ */
+async renameFilesToMovieCode(hash: string) : Promise<FileRename[]> {
+    return await TAURI_INVOKE("rename_files_to_movie_code", { hash });
+},
+/**
+ * 重命名种子中的文件夹
+ */
+async renameFolder(hash: string, oldPath: string, newPath: string) : Promise<null> {
+    return await TAURI_INVOKE("rename_folder", { hash, oldPath, newPath });
+},
+/**
+ * 重命名自动下载规则
+ */
+async renameRssRule(ruleName: string, newRuleName: string) : Promise<null> {
+    return await TAURI_INVOKE("rename_rss_rule", { ruleName, newRuleName });
+},
+/**
+ * 按规则重新排列下载队列，返回排序后的种子哈希
+ * 
+ * 发布日期取自已刮削的视频信息，没有信息的种子排在最后。未指定 `order` 时新发布的优先。
+ */
+async reorderQueue(order: QueueOrder | null) : Promise<string[]> {
+    return await TAURI_INVOKE("reorder_queue", { order });
+},
+/**
+ * 重新刮削
+ */
+async rescrape(name: string) : Promise<VideoInfo | null> {
+    return await TAURI_INVOKE("rescrape", { name });
+},
+/**
+ * 保存服务器配置
+ */
+async saveProfile(profile: Profile) : Promise<null> {
+    return await TAURI_INVOKE("save_profile", { profile });
+},
+/**
+ * 搜索名称中的番号，去除番号不同的结果
+ * 
+ * 等待搜索完成或超时后停止并删除搜索任务（出错时也是如此），名称中没有番号时返回空列表。
+ */
+async searchMovieCode(name: string) : Promise<SearchResult[]> {
+    return await TAURI_INVOKE("search_movie_code", { name });
+},
+/**
+ * 启用或禁用自动种子管理
+ */
+async setAutoManagement(hashes: string[], enable: boolean) : Promise<null> {
+    return await TAURI_INVOKE("set_auto_management", { hashes, enable });
+},
+/**
+ * 设置种子分类
+ */
+async setCategory(hashes: string[], category: string) : Promise<null> {
+    return await TAURI_INVOKE("set_category", { hashes, category });
+},
+/**
+ * 修改未完成下载的保存路径
+ */
+async setDownloadPath(hashes: string[], path: string) : Promise<null> {
+    return await TAURI_INVOKE("set_download_path", { hashes, path });
+},
+/**
+ * 设置文件优先级
+ */
+async setFilePriority(hash: string, indexes: number[], priority: number) : Promise<null> {
+    return await TAURI_INVOKE("set_file_priority", { hash, indexes, priority });
+},
+/**
+ * 设置强制开始
+ */
+async setForceStart(hashes: string[], value: boolean) : Promise<null> {
+    return await TAURI_INVOKE("set_force_start", { hashes, value });
+},
+/**
+ * 设置全局下载限速（字节/秒），0 表示不限速
+ */
+async setGlobalDownloadLimit(limit: number) : Promise<null> {
+    return await TAURI_INVOKE("set_global_download_limit", { limit });
+},
+/**
+ * 设置全局上传限速（字节/秒），0 表示不限速
+ */
+async setGlobalUploadLimit(limit: number) : Promise<null> {
+    return await TAURI_INVOKE("set_global_upload_limit", { limit });
+},
+/**
+ * 移动种子文件到新位置
+ * 
+ * 移动时种子状态为 `Moving`，完成后会发送 `TorrentMoveFinished` 事件。
+ */
+async setLocation(hashes: string[], location: string) : Promise<null> {
+    return await TAURI_INVOKE("set_location", { hashes, location });
+},
+/**
+ * 添加匹配番号前缀的自动下载规则
+ */
+async setMovieCodeRssRule(ruleName: string, prefixes: string[], affectedFeeds: string[], category: string | null) : Promise<null> {
+    return await TAURI_INVOKE("set_movie_code_rss_rule", { ruleName, prefixes, affectedFeeds, category });
+},
+/**
+ * 修改应用程序设置，只修改有值的字段
+ */
+async setPreferences(preferences: Preferences) : Promise<null> {
+    return await TAURI_INVOKE("set_preferences", { preferences });
+},
+/**
+ * 添加或修改自动下载规则
+ */
+async setRssRule(ruleName: string, rule: RssRule) : Promise<null> {
+    return await TAURI_INVOKE("set_rss_rule", { ruleName, rule });
+},
+/**
+ * 修改保存路径
+ * 
+ * 仅适用于未启用自动管理的种子，已下载的文件也会被移动。
+ */
+async setSavePath(hashes: string[], path: string) : Promise<null> {
+    return await TAURI_INVOKE("set_save_path", { hashes, path });
+},
+/**
+ * 保存自动选择文件的规则
+ */
+async setSelectRules(rules: SelectRules) : Promise<null> {
+    return await TAURI_INVOKE("set_select_rules", { rules });
+},
+/**
+ * 设置种子分享限制
+ * 
+ * -2 表示使用全局设置，-1 表示不限制；时间以分钟为单位。
+ */
+async setShareLimits(hashes: string[], ratioLimit: number, seedingTimeLimit: number, inactiveSeedingTimeLimit: number) : Promise<null> {
+    return await TAURI_INVOKE("set_share_limits", { hashes, ratioLimit, seedingTimeLimit, inactiveSeedingTimeLimit });
+},
+/**
+ * 设置超级做种
+ */
+async setSuperSeeding(hashes: string[], value: boolean) : Promise<null> {
+    return await TAURI_INVOKE("set_super_seeding", { hashes, value });
+},
+/**
+ * 保存种子缓存地址模板
+ */
+async setTorrentCaches(caches: string[]) : Promise<null> {
+    return await TAURI_INVOKE("set_torrent_caches", { caches });
+},
+/**
+ * 设置种子下载限速（字节/秒），0 表示不限速
+ */
+async setTorrentDownloadLimit(hashes: string[], limit: number) : Promise<null> {
+    return await TAURI_INVOKE("set_torrent_download_limit", { hashes, limit });
+},
+/**
+ * 设置种子上传限速（字节/秒），0 表示不限速
+ */
+async setTorrentUploadLimit(hashes: string[], limit: number) : Promise<null> {
+    return await TAURI_INVOKE("set_torrent_upload_limit", { hashes, limit });
+},
+/**
+ * 保存 Tracker 列表设置
+ */
+async setTrackerList(trackers: string[]) : Promise<null> {
+    return await TAURI_INVOKE("set_tracker_list", { trackers });
+},
+/**
+ * 开始
+ * 
+ * qBittorrent 5 之前使用 `torrents/resume`。
+ */
+async start(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("start", { hashes });
+},
+/**
+ * 开始搜索，返回搜索任务 ID
+ * 
+ * 未指定 `plugins` 时使用所有已启用的插件，未指定 `category` 时搜索所有分类。
+ */
+async startSearch(pattern: string, plugins: string[] | null, category: string | null) : Promise<number> {
+    return await TAURI_INVOKE("start_search", { pattern, plugins, category });
+},
+/**
+ * 开始后台同步
+ */
+async startSync() : Promise<null> {
+    return await TAURI_INVOKE("start_sync");
+},
+/**
+ * 停止
+ * 
+ * qBittorrent 5 之前使用 `torrents/pause`。
+ */
+async stop(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("stop", { hashes });
+},
+/**
+ * 停止搜索
+ */
+async stopSearch(id: number) : Promise<null> {
+    return await TAURI_INVOKE("stop_search", { id });
+},
+/**
+ * 停止后台同步
+ */
+async stopSync() : Promise<null> {
+    return await TAURI_INVOKE("stop_sync");
+},
+/**
+ * 切换服务器配置
+ * 
+ * 清空旧服务器同步的数据并为其中的种子发送删除事件，之后的同步使用新的服务器。
+ */
+async switchProfile(profileId: string) : Promise<null> {
+    return await TAURI_INVOKE("switch_profile", { profileId });
+},
+/**
+ * 切换优先下载首尾文件块
+ */
+async toggleFirstLastPiecePrio(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("toggle_first_last_piece_prio", { hashes });
+},
+/**
+ * 切换顺序下载
+ */
+async toggleSequentialDownload(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("toggle_sequential_download", { hashes });
+},
+/**
+ * 切换备用速度限制
+ */
+async toggleSpeedLimitsMode() : Promise<null> {
+    return await TAURI_INVOKE("toggle_speed_limits_mode");
+},
+/**
+ * 移至队列顶部
+ */
+async topPriority(hashes: string[]) : Promise<null> {
+    return await TAURI_INVOKE("top_priority", { hashes });
+},
+/**
+ * 将种子转移到另一个服务器
+ * 
+ * 以磁力链接添加到目标服务器并保留分类、标签与保存路径，`delete_source` 为 `true` 时从源服务器删除已添加的种子（保留文件）。
+ */
+async transferTorrents(from: string, to: string, hashes: string[], deleteSource: boolean) : Promise<null> {
+    return await TAURI_INVOKE("transfer_torrents", { from, to, hashes, deleteSource });
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+serverStateChanged: ServerStateChanged,
+torrentAdded: TorrentAdded,
+torrentCompleted: TorrentCompleted,
+torrentMoveFinished: TorrentMoveFinished,
+torrentRemoved: TorrentRemoved,
+torrentStateChanged: TorrentStateChanged,
+torrentUpdated: TorrentUpdated
+}>({
+serverStateChanged: "server-state-changed",
+torrentAdded: "torrent-added",
+torrentCompleted: "torrent-completed",
+torrentMoveFinished: "torrent-move-finished",
+torrentRemoved: "torrent-removed",
+torrentStateChanged: "torrent-state-changed",
+torrentUpdated: "torrent-updated"
+})
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+export type Actress = { name: string; photo: string | null }
+/**
+ * 添加种子的选项
+ */
+export type AddTorrentOptions = { 
+/**
+ * Download folder
+ */
+save_path?: string | null; 
+/**
+ * Incomplete download folder
+ */
+download_path?: string | null; 
+/**
+ * Whether the incomplete download folder is used
+ */
+use_download_path?: boolean | null; 
+/**
+ * Cookie sent to download the .torrent file
+ */
+cookie?: string | null; 
+/**
+ * Category for the torrent
+ */
+category?: string | null; 
+/**
+ * Tags for the torrent
+ */
+tags?: string[] | null; 
+/**
+ * Skip hash checking
+ */
+skip_checking?: boolean | null; 
+/**
+ * Add torrents in the stopped (paused) state
+ */
+stopped?: boolean | null; 
+/**
+ * Create the root folder. Superseded by `content_layout` since qBittorrent 4.3.2.
+ */
+root_folder?: boolean | null; 
+/**
+ * Content layout
+ */
+content_layout?: ContentLayout | null; 
+/**
+ * Rename torrent
+ */
+rename?: string | null; 
+/**
+ * Set torrent upload speed limit (bytes/second)
+ */
+up_limit?: number | null; 
+/**
+ * Set torrent download speed limit (bytes/second)
+ */
+dl_limit?: number | null; 
+/**
+ * Set torrent share ratio limit
+ */
+ratio_limit?: number | null; 
+/**
+ * Set torrent seeding time limit (minutes)
+ */
+seeding_time_limit?: number | null; 
+/**
+ * Set torrent inactive seeding time limit (minutes)
+ */
+inactive_seeding_time_limit?: number | null; 
+/**
+ * Whether Automatic Torrent Management should be used
+ */
+auto_tmm?: boolean | null; 
+/**
+ * Enable sequential download
+ */
+sequential_download?: boolean | null; 
+/**
+ * Prioritize download first last piece
+ */
+first_last_piece_prio?: boolean | null; 
+/**
+ * Stop condition
+ */
+stop_condition?: StopCondition | null; 
+/**
+ * Add torrents to the top of the queue
+ */
+add_to_top_of_queue?: boolean | null }
+export type BuildInfo = { 
+/**
+ * Qt version
+ */
+qt: string; 
+/**
+ * libtorrent version
+ */
+libtorrent: string; 
+/**
+ * Boost version
+ */
+boost: string; 
+/**
+ * OpenSSL version
+ */
+openssl: string; 
+/**
+ * Application bitness (e.g. 64-bit)
+ */
+bitness: number }
+export type Category = { 
+/**
+ * Category name
+ */
+name?: string | null; 
+/**
+ * Default save path of torrents in this category
+ */
+savePath?: string | null }
+export type ConnectionStatus = "connected" | "firewalled" | "disconnected"
+/**
+ * 内容布局
+ */
+export type ContentLayout = "Original" | "Subfolder" | "NoSubfolder"
+/**
+ * 文件重命名记录
+ */
+export type FileRename = { old_path: string; new_path: string }
+/**
+ * 链接检查结果
+ */
+export type LinkStatus = { kind: "new" } | 
+/**
+ * 已在 qBittorrent 中
+ */
+{ kind: "inClient" } | 
+/**
+ * 之前下载过，值为下载完成的时间
+ */
+{ kind: "downloadedBefore"; value: number } | 
+/**
+ * 无法解析的磁力链接
+ */
+{ kind: "invalid"; value: string }
+export type LinkVerdict = { link: string; 
+/**
+ * 磁力链接的解析结果，其他链接为空
+ */
+magnet: Magnet | null; movie_code: string | null; status: LinkStatus }
+export type LogEntry = { 
+/**
+ * ID of the message
+ */
+id: number; 
+/**
+ * Seconds since epoch
+ */
+timestamp: number; 
+/**
+ * Type of the message
+ */
+level: LogLevel; 
+/**
+ * Text of the message
+ */
+message: string }
+/**
+ * 日志级别
+ */
+export type LogLevel = "Normal" | "Info" | "Warning" | "Critical"
+/**
+ * 磁力链接
+ */
+export type Magnet = { 
+/**
+ * 小写的 v1 info hash
+ */
+info_hash_v1: string | null; 
+/**
+ * 小写的 v2 info hash
+ */
+info_hash_v2: string | null; 
+/**
+ * 显示名称（dn）
+ */
+display_name: string | null; 
+/**
+ * Tracker（tr）
+ */
+trackers: string[]; 
+/**
+ * 大小（xl）
+ */
+length: number | null }
+export type MainData = { 
+/**
+ * Whether the response contains all the data or partial data
+ */
+full_update?: boolean; 
+/**
+ * Response ID
+ */
+rid: number; 
+/**
+ * Global transfer info
+ */
+server_state?: ServerState | null; 
+/**
+ * Property: torrent hash, value: same as torrent list
+ */
+torrents?: { [key in string]: TorrentInfo } | null; 
+/**
+ * List of hashes of torrents removed since last request
+ */
+torrents_removed?: string[] | null; 
+/**
+ * Info for categories added since last request
+ */
+categories?: { [key in string]: Category } | null; 
+/**
+ * List of categories removed since last request
+ */
+categories_removed?: string[] | null; 
+/**
+ * List of tags added since last request
+ */
+tags?: string[] | null; 
+/**
+ * List of tags removed since last request
+ */
+tags_removed?: string[] | null }
+export type PeerInfo = { 
+/**
+ * Client name of the peer
+ */
+client?: string | null; 
+/**
+ * Client name parsed from peer ID
+ */
+peer_id_client?: string | null; 
+/**
+ * Connection type, e.g. BT, uTP
+ */
+connection?: string | null; country?: string | null; country_code?: string | null; 
+/**
+ * Download speed (bytes/s)
+ */
+dl_speed?: number | null; 
+/**
+ * Amount of data downloaded from the peer (bytes)
+ */
+downloaded?: number | null; 
+/**
+ * Files the peer is transferring
+ */
+files?: string | null; flags?: string | null; flags_desc?: string | null; ip?: string | null; port?: number | null; 
+/**
+ * Peer progress (percentage/100)
+ */
+progress?: number | null; 
+/**
+ * Relevance of the peer (percentage/100)
+ */
+relevance?: number | null; 
+/**
+ * Upload speed (bytes/s)
+ */
+up_speed?: number | null; 
+/**
+ * Amount of data uploaded to the peer (bytes)
+ */
+uploaded?: number | null }
+export type PeerLogEntry = { 
+/**
+ * ID of the peer
+ */
+id: number; 
+/**
+ * Seconds since epoch
+ */
+timestamp: number; 
+/**
+ * IP of the peer
+ */
+ip: string; 
+/**
+ * Whether or not the peer was blocked
+ */
+blocked: boolean; 
+/**
+ * Reason of the block
+ */
+reason: string }
+/**
+ * 应用程序设置
+ * 
+ * 所有字段均可为空，修改时只发送有值的字段。
+ */
+export type Preferences = { 
+/**
+ * Default save path for torrents, separated by slashes
+ */
+save_path?: string | null; 
+/**
+ * True if folder for incomplete torrents is enabled
+ */
+temp_path_enabled?: boolean | null; 
+/**
+ * Path for incomplete torrents, separated by slashes
+ */
+temp_path?: string | null; 
+/**
+ * Path to directory to copy .torrent files to
+ */
+export_dir?: string | null; 
+/**
+ * Path to directory to copy .torrent files of completed downloads to
+ */
+export_dir_fin?: string | null; 
+/**
+ * True if Automatic Torrent Management is enabled by default
+ */
+auto_tmm_enabled?: boolean | null; 
+/**
+ * True if torrent should be relocated when its Category's save path changes
+ */
+torrent_changed_tmm_enabled?: boolean | null; 
+/**
+ * True if torrent should be relocated when the default save path changes
+ */
+save_path_changed_tmm_enabled?: boolean | null; 
+/**
+ * True if torrent should be relocated when its Category changes
+ */
+category_changed_tmm_enabled?: boolean | null; 
+/**
+ * True if ".!qB" should be appended to incomplete files
+ */
+incomplete_files_ext?: boolean | null; 
+/**
+ * True if disk space should be pre-allocated for all files
+ */
+preallocate_all?: boolean | null; 
+/**
+ * Port for incoming connections
+ */
+listen_port?: number | null; 
+/**
+ * True if UPnP/NAT-PMP is enabled
+ */
+upnp?: boolean | null; 
+/**
+ * Maximum global number of simultaneous connections
+ */
+max_connec?: number | null; 
+/**
+ * Maximum number of simultaneous connections per torrent
+ */
+max_connec_per_torrent?: number | null; 
+/**
+ * Maximum number of upload slots
+ */
+max_uploads?: number | null; 
+/**
+ * Maximum number of upload slots per torrent
+ */
+max_uploads_per_torrent?: number | null; 
+/**
+ * Global download speed limit (bytes/second), 0 means unlimited
+ */
+dl_limit?: number | null; 
+/**
+ * Global upload speed limit (bytes/second), 0 means unlimited
+ */
+up_limit?: number | null; 
+/**
+ * Alternative global download speed limit (bytes/second)
+ */
+alt_dl_limit?: number | null; 
+/**
+ * Alternative global upload speed limit (bytes/second)
+ */
+alt_up_limit?: number | null; 
+/**
+ * True if torrent queuing is enabled
+ */
+queueing_enabled?: boolean | null; 
+/**
+ * Maximum number of active simultaneous downloads
+ */
+max_active_downloads?: number | null; 
+/**
+ * Maximum number of active simultaneous downloads and uploads
+ */
+max_active_torrents?: number | null; 
+/**
+ * Maximum number of active simultaneous uploads
+ */
+max_active_uploads?: number | null; 
+/**
+ * If true torrents w/o any activity (stalled ones) will not be counted towards `max_active_*` limits
+ */
+dont_count_slow_torrents?: boolean | null; 
+/**
+ * True if share ratio limit is enabled
+ */
+max_ratio_enabled?: boolean | null; 
+/**
+ * Get the global share ratio limit
+ */
+max_ratio?: number | null; 
+/**
+ * True if max seeding time limit is enabled
+ */
+max_seeding_time_enabled?: boolean | null; 
+/**
+ * Number of minutes to seed a torrent
+ */
+max_seeding_time?: number | null; 
+/**
+ * Action performed when a torrent reaches the maximum share ratio. 0: stop, 1: remove,
+ * 2: remove with files, 3: enable super seeding
+ */
+max_ratio_act?: number | null; 
+/**
+ * True if alternative limits should be applied according to schedule
+ */
+scheduler_enabled?: boolean | null; 
+/**
+ * Scheduler starting hour
+ */
+schedule_from_hour?: number | null; 
+/**
+ * Scheduler starting minute
+ */
+schedule_from_min?: number | null; 
+/**
+ * Scheduler ending hour
+ */
+schedule_to_hour?: number | null; 
+/**
+ * Scheduler ending minute
+ */
+schedule_to_min?: number | null; 
+/**
+ * Scheduler days. 0: every day, 1: every weekday, 2: every weekend, 3-9: Monday to Sunday
+ */
+scheduler_days?: number | null; 
+/**
+ * Currently selected language (e.g. en_GB for English)
+ */
+locale?: string | null; 
+/**
+ * Semicolon-separated list of domains to accept when performing Host header validation
+ */
+web_ui_domain_list?: string | null; 
+/**
+ * IP address to use for the WebUI
+ */
+web_ui_address?: string | null; 
+/**
+ * WebUI port
+ */
+web_ui_port?: number | null; 
+/**
+ * True if UPnP is used for the WebUI port
+ */
+web_ui_upnp?: boolean | null; 
+/**
+ * WebUI username
+ */
+web_ui_username?: string | null; 
+/**
+ * For API ≥ v2.3.0: Plaintext WebUI password, not readable, write-only
+ */
+web_ui_password?: string | null; 
+/**
+ * True if authentication challenge for loopback address (127.0.0.1) should be disabled
+ */
+bypass_local_auth?: boolean | null; 
+/**
+ * True if webui authentication should be bypassed for clients whose ip resides within (at least) one of the subnets on the whitelist
+ */
+bypass_auth_subnet_whitelist_enabled?: boolean | null; 
+/**
+ * (White)list of ipv4/ipv6 subnets for which webui authentication should be bypassed
+ */
+bypass_auth_subnet_whitelist?: string | null; 
+/**
+ * WebUI session timeout (seconds)
+ */
+web_ui_session_timeout?: number | null; 
+/**
+ * Maximum failed login attempts before the client is banned
+ */
+web_ui_max_auth_fail_count?: number | null; 
+/**
+ * Ban duration after too many failed login attempts (seconds)
+ */
+web_ui_ban_duration?: number | null; 
+/**
+ * True if WebUI HTTPS access is enabled
+ */
+use_https?: boolean | null }
+/**
+ * 服务器配置
+ */
+export type Profile = { 
+/**
+ * 配置 ID
+ */
+id: string; 
+/**
+ * 显示名称
+ */
+name: string; 
+/**
+ * WebUI 地址
+ */
+url: string; 
+/**
+ * 代理，为空字符串时不使用代理
+ */
+proxy: string | null; username: string; password: string }
+/**
+ * qBittorrent API 错误
+ */
+export type QBittorrentError = 
+/**
+ * 尚未设置服务器地址
+ */
+{ kind: "notInitialized" } | 
+/**
+ * 未登录，或会话过期后重新登录失败
+ */
+{ kind: "unauthorized" } | 
+/**
+ * 登录失败次数过多，IP 已被禁止
+ */
+{ kind: "banned" } | 
+/**
+ * 没有权限执行操作，例如保存路径不可写
+ */
+{ kind: "forbidden"; message: string } | 
+/**
+ * 找不到指定的种子
+ */
+{ kind: "notFound" } | 
+/**
+ * 请求与服务器状态冲突，例如分类名无效或路径无法使用
+ */
+{ kind: "conflict"; message: string } | 
+/**
+ * 种子文件无效
+ */
+{ kind: "invalidTorrent" } | 
+/**
+ * 网络、HTTP 或解析错误
+ */
+{ kind: "transport"; message: string } | 
+/**
+ * 找不到指定的服务器配置
+ */
+{ kind: "profileNotFound"; message: string } | 
+/**
+ * 读取设置等其他错误
+ */
+{ kind: "other"; message: string }
+/**
+ * 队列排序规则
+ */
+export type QueueOrder = 
+/**
+ * 发布日期较新的优先
+ */
+"NewestRelease" | 
+/**
+ * 发布日期较早的优先
+ */
+"OldestRelease" | 
+/**
+ * 体积较小的优先
+ */
+"SmallestFirst" | 
+/**
+ * 先添加的优先
+ */
+"FirstAdded"
+export type RssArticle = { id: string; title?: string | null; description?: string | null; date?: string | null; link?: string | null; torrentURL?: string | null; category?: string | null; author?: string | null; isRead?: boolean | null }
+export type RssFeed = { uid: string; url: string; title?: string | null; lastBuildDate?: string | null; isLoading?: boolean | null; hasError?: boolean | null; 
+/**
+ * 仅在 `with_data` 为 `true` 时返回
+ */
+articles?: RssArticle[] | null }
+/**
+ * RSS 订阅或文件夹
+ */
+export type RssItem = RssFeed | { [key in string]: RssItem }
+/**
+ * RSS 自动下载规则
+ */
+export type RssRule = { 
+/**
+ * Whether the rule is enabled
+ */
+enabled?: boolean | null; 
+/**
+ * The substring that the torrent name must contain
+ */
+mustContain?: string | null; 
+/**
+ * The substring that the torrent name must not contain
+ */
+mustNotContain?: string | null; 
+/**
+ * Enable regex mode in `must_contain` and `must_not_contain`
+ */
+useRegex?: boolean | null; 
+/**
+ * Episode filter definition
+ */
+episodeFilter?: string | null; 
+/**
+ * Enable smart episode filter
+ */
+smartFilter?: boolean | null; 
+/**
+ * The list of episode IDs already matched by smart filter
  */
-async addUrls(urls: string) : Promise<null> {
-    return await TAURI_INVOKE("add_urls", { urls });
-},
+previouslyMatchedEpisodes?: string[] | null; 
 /**
- * 删除
+ * The feed URLs the rule applies to
  */
-async delete(hashes: string[]) : Promise<null> {
-    return await TAURI_INVOKE("delete", { hashes });
-},
-async downloadImage(url: string) : Promise<string> {
-    return await TAURI_INVOKE("download_image", { url });
-},
+affectedFeeds?: string[] | null; 
 /**
- * 获取主要数据
+ * Ignore subsequent rule matches (days)
  */
-async getMainData() : Promise<MainData> {
-    return await TAURI_INVOKE("get_main_data");
-},
+ignoreDays?: number | null; 
 /**
- * 获取番号
+ * The rule last match time
  */
-async guessMovieCode(name: string) : Promise<string | null> {
-    return await TAURI_INVOKE("guess_movie_code", { name });
-},
+lastMatch?: string | null; 
 /**
- * 获取种子内容
+ * Add matched torrent in paused mode
  */
-async getTorrentContents(hash: string) : Promise<TorrentContent[]> {
-    return await TAURI_INVOKE("get_torrent_contents", { hash });
-},
+addPaused?: boolean | null; 
 /**
- * 获取视频信息
+ * Assign category to the torrent
  */
-async getVideoInfo(name: string) : Promise<VideoInfo | null> {
-    return await TAURI_INVOKE("get_video_info", { name });
-},
+assignedCategory?: string | null; 
 /**
- * 之前是否下载过
+ * Save torrent to the given directory
  */
-async hasBeenDownloaded(name: string, hash: string | null) : Promise<number | null> {
-    return await TAURI_INVOKE("has_been_downloaded", { name, hash });
-},
+savePath?: string | null }
+export type SearchCategory = { id: string; name: string }
+export type SearchJobStatus = "Running" | "Stopped"
+export type SearchPlugin = { 
 /**
- * 设置 URL
+ * Whether the plugin is enabled
  */
-async initialize(url: string, proxy: string | null) : Promise<null> {
-    return await TAURI_INVOKE("initialize", { url, proxy });
-},
+enabled: boolean; 
 /**
- * 登录
+ * Full name of the plugin
  */
-async login(username: string, password: string) : Promise<boolean> {
-    return await TAURI_INVOKE("login", { username, password });
-},
+fullName: string; 
 /**
- * 标记为已下载
+ * Short name of the plugin
  */
-async markAsDownloaded(name: string, hash: string | null, downloadedAt: number) : Promise<null> {
-    return await TAURI_INVOKE("mark_as_downloaded", { name, hash, downloadedAt });
-},
+name: string; 
 /**
- * 重新校验
+ * List of category objects
  */
-async recheck(hashes: string[]) : Promise<null> {
-    return await TAURI_INVOKE("recheck", { hashes });
-},
+supportedCategories?: SearchCategory[] | null; 
 /**
- * 重命名
+ * URL of the torrent site
  */
-async rename(hash: string, name: string) : Promise<null> {
-    return await TAURI_INVOKE("rename", { hash, name });
-},
+url?: string | null; 
 /**
- * 重新刮削
+ * Installed version of the plugin
  */
-async rescrape(name: string) : Promise<VideoInfo | null> {
-    return await TAURI_INVOKE("rescrape", { name });
-},
+version?: string | null }
+export type SearchResult = { 
 /**
- * 设置文件优先级
+ * URL of the torrent's description page
  */
-async setFilePriority(hash: string, indexes: number[], priority: number) : Promise<null> {
-    return await TAURI_INVOKE("set_file_priority", { hash, indexes, priority });
-},
+descrLink?: string | null; 
 /**
- * 开始
+ * Name of the file
  */
-async start(hashes: string[]) : Promise<null> {
-    return await TAURI_INVOKE("start", { hashes });
-},
+fileName: string; 
 /**
- * 停止
+ * Size of the file in Bytes
  */
-async stop(hashes: string[]) : Promise<null> {
-    return await TAURI_INVOKE("stop", { hashes });
-}
-}
-
-/** user-defined events **/
-
-
-
-/** user-defined constants **/
-
-
-
-/** user-defined types **/
-
-export type Actress = { name: string; photo: string | null }
-export type ConnectionStatus = "connected" | "firewalled" | "disconnected"
-export type MainData = { 
+fileSize: number; 
 /**
- * Whether the response contains all the data or partial data
+ * Torrent download link (usually either .torrent file or magnet link)
  */
-full_update?: boolean; 
+fileUrl: string; 
 /**
- * Response ID
+ * Number of leechers
  */
-rid: number; 
+nbLeechers: number; 
 /**
- * Global transfer info
+ * Number of seeders
  */
-server_state?: ServerState | null; 
+nbSeeders: number; 
 /**
- * Property: torrent hash, value: same as torrent list
+ * URL of the torrent site
  */
-torrents?: { [key in string]: TorrentInfo } | null; 
+siteUrl?: string | null }
+export type SearchResults = { results: SearchResult[]; 
 /**
- * List of hashes of torrents removed since last request
+ * Current status of the search job
+ */
+status: SearchJobStatus; 
+/**
+ * Total number of results
+ */
+total: number }
+export type SearchStatus = { 
+/**
+ * ID of the search job
+ */
+id: number; 
+/**
+ * Current status of the search job
+ */
+status: SearchJobStatus; 
+/**
+ * Total number of results
+ */
+total: number }
+/**
+ * 自动选择文件的规则
+ */
+export type SelectRules = { 
+/**
+ * 小于此大小（字节）的视频视为广告，其他文件小于此大小时不下载
+ */
+smallFileThreshold: number; 
+/**
+ * 文件名包含这些关键字时视为预览
+ */
+sampleKeywords: string[]; 
+/**
+ * 文件名包含这些关键字时视为广告
  */
-torrents_removed?: string[] | null }
+advertisementKeywords: string[]; keepSubtitles: boolean; keepImages: boolean; keepArchives: boolean }
 export type ServerState = { alltime_dl?: number | null; alltime_ul?: number | null; average_time_queue?: number | null; 
 /**
  * Connection status
@@ -191,6 +1520,38 @@ up_rate_limit?: number | null;
  * True if alternative speed limits are enabled
  */
 use_alt_speed_limits?: boolean | null; use_subcategories?: boolean | null; write_cache_overload?: string | null }
+/**
+ * 全局传输信息改变
+ */
+export type ServerStateChanged = { server_state: ServerState }
+/**
+ * 自动停止条件
+ */
+export type StopCondition = "None" | "MetadataReceived" | "FilesChecked"
+/**
+ * 添加了种子
+ */
+export type TorrentAdded = { hash: string; info: TorrentInfo }
+/**
+ * 两个服务器的种子对比结果
+ */
+export type TorrentComparison = { 
+/**
+ * 只在第一个服务器中的种子
+ */
+only_in_first: TorrentInfo[]; 
+/**
+ * 只在第二个服务器中的种子
+ */
+only_in_second: TorrentInfo[]; 
+/**
+ * 两个服务器中都有的种子哈希
+ */
+in_both: string[] }
+/**
+ * 种子下载完成
+ */
+export type TorrentCompleted = { hash: string; name: string | null }
 export type TorrentContent = { 
 /**
  * File index
@@ -221,6 +1582,22 @@ piece_range: number[];
  * Percentage of file pieces currently available
  */
 availability: number }
+/**
+ * 种子中的文件
+ */
+export type TorrentFile = { 
+/**
+ * 文件序号，与 qBittorrent 中的序号一致
+ */
+index: number; 
+/**
+ * 包括种子名称的相对路径
+ */
+path: string; 
+/**
+ * 文件大小（字节）
+ */
+size: number }
 export type TorrentInfo = { 
 /**
  * Time (Unix Epoch) when the torrent was added to the client
@@ -281,7 +1658,7 @@ force_start?: boolean | null;
 /**
  * Torrent hash
  */
-infohash_v1?: string | null; infohash_v2?: string | null; 
+hash?: string | null; infohash_v1?: string | null; infohash_v2?: string | null; 
 /**
  * Last time (Unix Epoch) when a chunk was downloaded/uploaded
  */
@@ -363,9 +1740,9 @@ state?: TorrentState | null;
  */
 super_seeding?: boolean | null; 
 /**
- * Comma-concatenated tag list of the torrent
+ * Tag list of the torrent
  */
-tags?: string | null; 
+tags?: string[] | null; 
 /**
  * Total active time (seconds)
  */
@@ -394,7 +1771,243 @@ uploaded_session?: number | null;
  * Torrent upload speed (bytes/s)
  */
 upspeed?: number | null }
-export type TorrentState = "error" | "missingFiles" | "uploading" | "stoppedUP" | "queuedUP" | "stalledUP" | "checkingUP" | "forcedUP" | "allocating" | "downloading" | "metaDL" | "stoppedDL" | "queuedDL" | "stalledDL" | "checkingDL" | "forcedDL" | "checkingResumeData" | "moving" | "unknown"
+/**
+ * 种子移动完成
+ */
+export type TorrentMoveFinished = { hash: string; save_path: string | null }
+/**
+ * 种子文件预览
+ */
+export type TorrentPreview = { name: string; 
+/**
+ * v1 info hash，v2 种子为空
+ */
+info_hash_v1: string | null; 
+/**
+ * v2 info hash，v1 种子为空
+ */
+info_hash_v2: string | null; files: TorrentFile[]; total_size: number; piece_length: number | null; private: boolean; trackers: string[]; comment: string | null; created_by: string | null; 
+/**
+ * 创建时间（Unix epoch）
+ */
+creation_date: number | null; 
+/**
+ * 从名称中识别的番号
+ */
+movie_code: string | null; 
+/**
+ * 之前下载完成的时间
+ */
+downloaded_at: number | null }
+export type TorrentProperties = { 
+/**
+ * Torrent hash
+ */
+hash?: string | null; infohash_v1?: string | null; infohash_v2?: string | null; 
+/**
+ * Torrent name
+ */
+name?: string | null; 
+/**
+ * Torrent save path
+ */
+save_path?: string | null; 
+/**
+ * Torrent download path
+ */
+download_path?: string | null; 
+/**
+ * Torrent creation date (Unix timestamp)
+ */
+creation_date?: number | null; 
+/**
+ * Torrent piece size (bytes)
+ */
+piece_size?: number | null; 
+/**
+ * Torrent comment
+ */
+comment?: string | null; 
+/**
+ * Total data wasted for torrent (bytes)
+ */
+total_wasted?: number | null; 
+/**
+ * Total data uploaded for torrent (bytes)
+ */
+total_uploaded?: number | null; 
+/**
+ * Total data uploaded this session (bytes)
+ */
+total_uploaded_session?: number | null; 
+/**
+ * Total data downloaded for torrent (bytes)
+ */
+total_downloaded?: number | null; 
+/**
+ * Total data downloaded this session (bytes)
+ */
+total_downloaded_session?: number | null; 
+/**
+ * Torrent upload limit (bytes/s)
+ */
+up_limit?: number | null; 
+/**
+ * Torrent download limit (bytes/s)
+ */
+dl_limit?: number | null; 
+/**
+ * Torrent elapsed time (seconds)
+ */
+time_elapsed?: number | null; 
+/**
+ * Torrent elapsed time while complete (seconds)
+ */
+seeding_time?: number | null; 
+/**
+ * Torrent connection count
+ */
+nb_connections?: number | null; 
+/**
+ * Torrent connection count limit
+ */
+nb_connections_limit?: number | null; 
+/**
+ * Torrent share ratio
+ */
+share_ratio?: number | null; 
+/**
+ * When this torrent was added (unix timestamp)
+ */
+addition_date?: number | null; 
+/**
+ * Torrent completion date (unix timestamp)
+ */
+completion_date?: number | null; 
+/**
+ * Torrent creator
+ */
+created_by?: string | null; 
+/**
+ * Torrent average download speed (bytes/second)
+ */
+dl_speed_avg?: number | null; 
+/**
+ * Torrent download speed (bytes/second)
+ */
+dl_speed?: number | null; 
+/**
+ * Torrent ETA (seconds)
+ */
+eta?: number | null; 
+/**
+ * Last seen complete date (unix timestamp)
+ */
+last_seen?: number | null; 
+/**
+ * Number of peers connected to
+ */
+peers?: number | null; 
+/**
+ * Number of peers in the swarm
+ */
+peers_total?: number | null; 
+/**
+ * Number of pieces owned
+ */
+pieces_have?: number | null; 
+/**
+ * Number of pieces of the torrent
+ */
+pieces_num?: number | null; 
+/**
+ * Number of seconds until the next announce
+ */
+reannounce?: number | null; 
+/**
+ * Number of seeds connected to
+ */
+seeds?: number | null; 
+/**
+ * Number of seeds in the swarm
+ */
+seeds_total?: number | null; 
+/**
+ * Torrent total size (bytes)
+ */
+total_size?: number | null; 
+/**
+ * Torrent average upload speed (bytes/second)
+ */
+up_speed_avg?: number | null; 
+/**
+ * Torrent upload speed (bytes/second)
+ */
+up_speed?: number | null; 
+/**
+ * True if torrent is from a private tracker
+ */
+is_private?: boolean | null }
+/**
+ * 删除了种子
+ */
+export type TorrentRemoved = { hash: string }
+export type TorrentState = "error" | "missingFiles" | "uploading" | 
+/**
+ * qBittorrent 5 之前为 `pausedUP`
+ */
+"stoppedUP" | "queuedUP" | "stalledUP" | "checkingUP" | "forcedUP" | "allocating" | "downloading" | "metaDL" | 
+/**
+ * qBittorrent 5 之前为 `pausedDL`
+ */
+"stoppedDL" | "queuedDL" | "stalledDL" | "checkingDL" | "forcedDL" | "checkingResumeData" | "moving" | 
+/**
+ * 无法识别的状态
+ */
+"unknown"
+/**
+ * 种子状态改变
+ */
+export type TorrentStateChanged = { hash: string; old_state: TorrentState | null; new_state: TorrentState | null }
+/**
+ * 种子信息改变，`info` 为合并后的完整信息
+ */
+export type TorrentUpdated = { hash: string; info: TorrentInfo }
+export type Tracker = { 
+/**
+ * Tracker url
+ */
+url: string; 
+/**
+ * Tracker status. 0: disabled (used for DHT, PeX, and LSD), 1: not contacted yet,
+ * 2: working, 3: updating, 4: not working
+ */
+status: number; 
+/**
+ * Tracker priority tier. Lower tier trackers are tried before higher tiers.
+ * Tier numbers are valid when `>= 0`, `< 0` is used as placeholder when `tier` does not exist for special entries (such as DHT).
+ */
+tier: number; 
+/**
+ * Number of peers for current torrent, as reported by the tracker
+ */
+num_peers?: number | null; 
+/**
+ * Number of seeds for current torrent, as reported by the tracker
+ */
+num_seeds?: number | null; 
+/**
+ * Number of leeches for current torrent, as reported by the tracker
+ */
+num_leeches?: number | null; 
+/**
+ * Number of completed downloads for current torrent, as reported by the tracker
+ */
+num_downloaded?: number | null; 
+/**
+ * Tracker message (there is no way of knowing what this message is - it's up to tracker admins)
+ */
+msg?: string | null }
 export type TranslatedText = { text: string; translated: string | null }
 /**
  * 视频信息
@@ -456,6 +2069,11 @@ release_date: number | null;
  * 额外的插图
  */
 extra_fanart: string[] | null }
+export type WebSeed = { 
+/**
+ * URL of the web seed
+ */
+url: string }
 
 /** tauri-specta globals **/
 
@@ -1,5 +1,3 @@
-import { assign } from 'radashi';
-
 import type { MainData, ServerState, TorrentInfo, TorrentState } from './bindings';
 
 type NonNullableFields<T> = {
@@ -105,18 +103,14 @@ export const matchTorrent = (torrent: RequiredTorrentInfo, filter: TorrentFilter
   return states.includes(torrent.state);
 };
 
-export const mergeMainData = (data: RequiredMainData, delta: MainData) => {
-  let merged = assign(data, delta) as RequiredMainData;
-
-  if (Array.isArray(delta.torrents_removed) && delta.torrents_removed.length > 0) {
-    for (const hash of delta.torrents_removed) {
-      delete merged.torrents[hash];
-    }
-  }
-
-  merged.torrents_removed = [];
-  return merged;
-};
+export const fromSnapshot = (data: MainData) =>
+  ({
+    full_update: true,
+    rid: data.rid,
+    server_state: { ...defaultServerState, ...data.server_state },
+    torrents: data.torrents ?? {},
+    torrents_removed: [],
+  }) as RequiredMainData;
 
 export const getInfoHash = (torrent: RequiredTorrentInfo) => torrent.infohash_v1;
 export const getInfoHashes = (torrents: RequiredTorrentInfo[]) => torrents.map(getInfoHash);